@@ -0,0 +1,85 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use eq_float::F64;
+
+mod common;
+
+use common::random_floats;
+
+/// The comparison `Ord` used before the branch-free key: `partial_cmp`, with a branch that
+/// handles `NAN`s first.
+fn baseline_cmp(a: &F64, b: &F64) -> Ordering {
+    match (a.0.is_nan(), b.0.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a.0.partial_cmp(&b.0).unwrap(),
+    }
+}
+
+/// An `F64` ordered by `baseline_cmp`, to use as a `BTreeMap` key.
+#[derive(Clone, Copy)]
+struct Baseline(F64);
+
+impl PartialEq for Baseline {
+    fn eq(&self, other: &Self) -> bool {
+        baseline_cmp(&self.0, &other.0) == Ordering::Equal
+    }
+}
+
+impl Eq for Baseline {}
+
+impl PartialOrd for Baseline {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Baseline {
+    fn cmp(&self, other: &Self) -> Ordering {
+        baseline_cmp(&self.0, &other.0)
+    }
+}
+
+fn cmp(c: &mut Criterion) {
+    let input = random_floats(100_000);
+    let mut group = c.benchmark_group("cmp");
+    group.bench_function("sort_unstable", |b| {
+        b.iter_batched_ref(
+            || input.clone(),
+            |v| black_box(v).sort_unstable(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("sort_unstable_baseline", |b| {
+        b.iter_batched_ref(
+            || input.clone(),
+            |v| black_box(v).sort_unstable_by(baseline_cmp),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("btree_map_insert", |b| {
+        b.iter(|| {
+            let mut map = BTreeMap::new();
+            for (i, x) in input.iter().enumerate() {
+                map.insert(*x, i);
+            }
+            black_box(map)
+        })
+    });
+    group.bench_function("btree_map_insert_baseline", |b| {
+        b.iter(|| {
+            let mut map = BTreeMap::new();
+            for (i, x) in input.iter().enumerate() {
+                map.insert(Baseline(*x), i);
+            }
+            black_box(map)
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, cmp);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+//! Inputs shared by the benchmarks.
+#![allow(dead_code)]
+
+use eq_float::F64;
+
+/// An endless stream of pseudo-random bits from a xorshift generator, so that every run of a
+/// benchmark sees the same input.
+pub fn random_bits() -> impl Iterator<Item = u64> {
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    std::iter::repeat_with(move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    })
+}
+
+/// Floats uniformly spread over `[-1e6, 1e6)`, with every thousandth one `NAN`.
+pub fn random_floats(len: usize) -> Vec<F64> {
+    random_bits()
+        .take(len)
+        .enumerate()
+        .map(|(i, state)| {
+            if i % 1000 == 0 {
+                F64(f64::NAN)
+            } else {
+                F64((state >> 11) as f64 / (1u64 << 53) as f64 * 2.0e6 - 1.0e6)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,36 @@
+use std::fmt::Write;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use eq_float::F64;
+
+mod common;
+
+fn fmt(c: &mut Criterion) {
+    let input: Vec<F64> = common::random_bits()
+        .take(10_000)
+        .map(|bits| F64(f64::from_bits(bits)))
+        .collect();
+    let mut group = c.benchmark_group("fmt");
+    group.bench_function("display", |b| {
+        let mut s = String::new();
+        b.iter(|| {
+            for x in &input {
+                s.clear();
+                write!(s, "{}", black_box(x)).unwrap();
+            }
+        })
+    });
+    group.bench_function("format_shortest", |b| {
+        let mut s = String::new();
+        b.iter(|| {
+            for x in &input {
+                s.clear();
+                black_box(*x).format_shortest(&mut s).unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, fmt);
+criterion_main!(benches);
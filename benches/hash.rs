@@ -0,0 +1,99 @@
+use std::collections::hash_map::DefaultHasher;
+use std::convert::TryInto;
+use std::hash::{Hash, Hasher};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use eq_float::F64;
+
+mod common;
+
+/// A hasher in the style of `FxHash`: a multiply and a rotate per word, so that the cost of
+/// producing the hashed bytes dominates, unlike with SipHash.
+#[derive(Default)]
+struct FxHasher(u64);
+
+impl FxHasher {
+    fn add(&mut self, word: u64) {
+        self.0 = (self.0.rotate_left(5) ^ word).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_ne_bytes(chunk.try_into().unwrap()));
+        }
+        for &byte in chunks.remainder() {
+            self.add(u64::from(byte));
+        }
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+fn hash(c: &mut Criterion) {
+    let key: [F64; 16] = [
+        F64(1.0),
+        F64(-0.0),
+        F64(f64::NAN),
+        F64(2.5),
+        F64(f64::INFINITY),
+        F64(1e-310),
+        F64(-3.75),
+        F64(0.0),
+        F64(42.0),
+        F64(-f64::NAN),
+        F64(0.1),
+        F64(1e300),
+        F64(-7.0),
+        F64(8.0),
+        F64(f64::NEG_INFINITY),
+        F64(0.5),
+    ];
+    c.bench_function("hash_f64_array_16", |b| {
+        b.iter(|| {
+            let mut hasher = DefaultHasher::new();
+            black_box(&key).hash(&mut hasher);
+            hasher.finish()
+        })
+    });
+    c.bench_function("fx_hash_f64_array_16", |b| {
+        b.iter(|| {
+            let mut hasher = FxHasher::default();
+            black_box(&key).hash(&mut hasher);
+            hasher.finish()
+        })
+    });
+
+    let values = common::random_floats(10_000);
+    c.bench_function("fx_hash_f64_slice_10000", |b| {
+        b.iter(|| {
+            let mut hasher = FxHasher::default();
+            black_box(&values[..]).hash(&mut hasher);
+            hasher.finish()
+        })
+    });
+    c.bench_function("fx_hash_f64_each_10000", |b| {
+        b.iter(|| {
+            let mut hasher = FxHasher::default();
+            for x in black_box(&values[..]) {
+                x.hash(&mut hasher);
+            }
+            hasher.finish()
+        })
+    });
+}
+
+criterion_group!(benches, hash);
+criterion_main!(benches);
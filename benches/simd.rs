@@ -0,0 +1,32 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use eq_float::{simd_sum_f32, simd_total_max_f32, simd_total_min_f32, F32};
+
+mod common;
+
+fn simd(c: &mut Criterion) {
+    let input: Vec<f32> = common::random_floats(1_000_000)
+        .into_iter()
+        .map(|x| x.0 as f32)
+        .collect();
+    let mut group = c.benchmark_group("simd");
+    group.bench_function("total_min_scalar", |b| {
+        b.iter(|| black_box(&input).iter().map(|&x| F32(x)).min())
+    });
+    group.bench_function("total_min_simd", |b| {
+        b.iter(|| simd_total_min_f32(black_box(&input)))
+    });
+    group.bench_function("total_max_scalar", |b| {
+        b.iter(|| black_box(&input).iter().map(|&x| F32(x)).max())
+    });
+    group.bench_function("total_max_simd", |b| {
+        b.iter(|| simd_total_max_f32(black_box(&input)))
+    });
+    group.bench_function("sum_scalar", |b| {
+        b.iter(|| black_box(&input).iter().sum::<f32>())
+    });
+    group.bench_function("sum_simd", |b| b.iter(|| simd_sum_f32(black_box(&input))));
+    group.finish();
+}
+
+criterion_group!(benches, simd);
+criterion_main!(benches);
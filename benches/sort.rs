@@ -0,0 +1,50 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use eq_float::F64;
+
+mod common;
+
+use common::random_floats;
+
+fn sort(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sort");
+    for len in [1_000, 100_000, 1_000_000] {
+        let input = random_floats(len);
+        group.bench_with_input(
+            BenchmarkId::new("sort_unstable", len),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| v.sort_unstable(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("sort_unstable_floats", len),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| F64::sort_unstable_floats(black_box(v)),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("radix_sort_floats", len),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| F64::radix_sort_floats(black_box(v)),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, sort);
+criterion_main!(benches);
@@ -0,0 +1,118 @@
+//! `approx` support. The comparisons delegate to the primitive implementations, with the
+//! primitive float as the `Epsilon` type (so `epsilon = 1e-6` works as usual). Following the
+//! primitives, `NAN` is never approximately equal to anything, unlike with `Eq`.
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{F32, F64};
+
+impl AbsDiffEq for F32 {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        <f32 as AbsDiffEq>::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+impl RelativeEq for F32 {
+    fn default_max_relative() -> f32 {
+        <f32 as RelativeEq>::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for F32 {
+    fn default_max_ulps() -> u32 {
+        <f32 as UlpsEq>::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+    }
+}
+
+impl AbsDiffEq for F64 {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        <f64 as AbsDiffEq>::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+impl RelativeEq for F64 {
+    fn default_max_relative() -> f64 {
+        <f64 as RelativeEq>::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for F64 {
+    fn default_max_ulps() -> u32 {
+        <f64 as UlpsEq>::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{
+        assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_relative_ne,
+        assert_ulps_eq, assert_ulps_ne,
+    };
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn approx_abs_diff() {
+        assert_abs_diff_eq!(F64(1.0), F64(1.0 + 1e-12), epsilon = 1e-9);
+        assert_abs_diff_eq!(F32(1.0), F32(1.05), epsilon = 0.1);
+        assert_abs_diff_ne!(F32(1.0), F32(1.2), epsilon = 0.1);
+        assert_abs_diff_ne!(F64(f64::NAN), F64(f64::NAN));
+    }
+
+    #[test]
+    fn approx_relative() {
+        assert_relative_eq!(F32(1.0), F32(1.000_000_1), max_relative = 1e-6);
+        assert_relative_eq!(F64(1e10), F64(1e10 + 1.0), max_relative = 1e-9);
+        assert_relative_ne!(F64(1e10), F64(1.1e10), max_relative = 1e-9);
+    }
+
+    #[test]
+    fn approx_ulps() {
+        let one = F32(1.0);
+        let next = F32(f32::from_bits(1.0f32.to_bits() + 1));
+        assert_ulps_eq!(one, next, epsilon = 0.0, max_ulps = 1);
+        assert_ulps_ne!(one, next, epsilon = 0.0, max_ulps = 0);
+
+        let one = F64(1.0);
+        let next = F64(f64::from_bits(1.0f64.to_bits() + 1));
+        assert_ulps_eq!(one, next, epsilon = 0.0, max_ulps = 1);
+        assert_ulps_ne!(one, next, epsilon = 0.0, max_ulps = 0);
+    }
+
+    #[test]
+    fn approx_slices() {
+        let a = [F64(1.0), F64(2.0)];
+        let b = [F64(1.0), F64(2.0 + 1e-15)];
+        assert_abs_diff_eq!(a[..], b[..], epsilon = 1e-9);
+        assert_relative_eq!(a[..], b[..], max_relative = 1e-9);
+        assert_ulps_eq!(a[..], b[..]);
+    }
+}
@@ -0,0 +1,115 @@
+//! `arbitrary` support. Values are generated from arbitrary bit patterns, so every float
+//! (including `NAN` payloads, subnormals and `-0.0`) can be produced.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{F32, F64};
+
+impl<'a> Arbitrary<'a> for F32 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u32::arbitrary(u).map(f32::from_bits).map(F32)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u32::arbitrary_take_rest(u).map(f32::from_bits).map(F32)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for F64 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u64::arbitrary(u).map(f64::from_bits).map(F64)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u64::arbitrary_take_rest(u).map(f64::from_bits).map(F64)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::{F32, F64};
+
+    const BYTES: [u8; 16] = [
+        0x00, 0x00, 0xc0, 0x7f, 0x01, 0x00, 0x80, 0xff, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde,
+        0xf0,
+    ];
+
+    // A small deterministic byte stream, so the tests don't need a source of randomness.
+    fn pseudo_random_bytes(len: usize) -> Vec<u8> {
+        crate::tests::random_bits_u64(0x2545_f491_4f6c_dd1d)
+            .take(len)
+            .map(|state| (state >> 56) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn arbitrary_deterministic() {
+        let mut u = Unstructured::new(&BYTES);
+        let mut v = Unstructured::new(&BYTES);
+        for _ in 0..4 {
+            let f = F32::arbitrary(&mut u).unwrap();
+            let bits = u32::arbitrary(&mut v).unwrap();
+            assert_eq!(f.0.to_bits(), bits);
+        }
+
+        let mut u = Unstructured::new(&BYTES);
+        let mut v = Unstructured::new(&BYTES);
+        for _ in 0..2 {
+            let f = F64::arbitrary(&mut u).unwrap();
+            let bits = u64::arbitrary(&mut v).unwrap();
+            assert_eq!(f.0.to_bits(), bits);
+        }
+
+        let f = F32::arbitrary_take_rest(Unstructured::new(&BYTES[..4])).unwrap();
+        let bits = u32::arbitrary_take_rest(Unstructured::new(&BYTES[..4])).unwrap();
+        assert_eq!(f.0.to_bits(), bits);
+        let f = F64::arbitrary_take_rest(Unstructured::new(&BYTES[..8])).unwrap();
+        let bits = u64::arbitrary_take_rest(Unstructured::new(&BYTES[..8])).unwrap();
+        assert_eq!(f.0.to_bits(), bits);
+    }
+
+    #[test]
+    fn arbitrary_size_hint() {
+        assert_eq!(F32::size_hint(0), (4, Some(4)));
+        assert_eq!(F64::size_hint(0), (8, Some(8)));
+    }
+
+    #[test]
+    fn arbitrary_reaches_nan() {
+        let bytes = pseudo_random_bytes(8 * 65536);
+
+        let mut u = Unstructured::new(&bytes);
+        let mut nans = 0;
+        while let Ok(f) = F32::arbitrary(&mut u) {
+            if u.is_empty() {
+                break;
+            }
+            if f.0.is_nan() {
+                nans += 1;
+            }
+        }
+        assert!(nans > 0);
+
+        let mut u = Unstructured::new(&bytes);
+        let mut nans = 0;
+        while let Ok(f) = F64::arbitrary(&mut u) {
+            if u.is_empty() {
+                break;
+            }
+            if f.0.is_nan() {
+                nans += 1;
+            }
+        }
+        assert!(nans > 0);
+    }
+}
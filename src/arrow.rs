@@ -0,0 +1,77 @@
+//! Zero-copy access to Arrow `Float32Array`s and `Float64Array`s as slices of `F32` and `F64`.
+//!
+//! The wrappers can not act as Arrow native types themselves, since `ArrowNativeType` is sealed,
+//! but as they are `#[repr(transparent)]`, the value buffers of float arrays can be viewed as
+//! slices of wrappers directly.
+
+use arrow_array::{Array, Float32Array, Float64Array};
+
+use crate::{F32, F64};
+
+impl F32 {
+    /// Views the values of the array as a slice of `F32`, or returns `None` if the array contains
+    /// nulls (whose slots in the value buffer hold arbitrary values).
+    pub fn from_arrow_slice(array: &Float32Array) -> Option<&[F32]> {
+        if array.null_count() == 0 {
+            Some(F32::wrap_slice(array.values()))
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the array, mapping nulls to `None`.
+    pub fn iter_arrow(array: &Float32Array) -> impl Iterator<Item = Option<F32>> + '_ {
+        array.iter().map(|f| f.map(F32))
+    }
+}
+
+impl F64 {
+    /// Views the values of the array as a slice of `F64`, or returns `None` if the array contains
+    /// nulls (whose slots in the value buffer hold arbitrary values).
+    pub fn from_arrow_slice(array: &Float64Array) -> Option<&[F64]> {
+        if array.null_count() == 0 {
+            Some(F64::wrap_slice(array.values()))
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the array, mapping nulls to `None`.
+    pub fn iter_arrow(array: &Float64Array) -> impl Iterator<Item = Option<F64>> + '_ {
+        array.iter().map(|f| f.map(F64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{Float32Array, Float64Array};
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn from_arrow_slice() {
+        let array = Float64Array::from(vec![1.5, f64::NAN, -0.0]);
+        assert_eq!(
+            F64::from_arrow_slice(&array),
+            Some(&[F64(1.5), F64(f64::NAN), F64(0.0)][..])
+        );
+        assert!(std::ptr::eq(
+            F64::from_arrow_slice(&array).unwrap().as_ptr() as *const f64,
+            array.values().as_ptr()
+        ));
+
+        let array = Float32Array::from(vec![Some(1.5), None]);
+        assert_eq!(F32::from_arrow_slice(&array), None);
+    }
+
+    #[test]
+    fn iter_arrow() {
+        let array = Float64Array::from(vec![Some(f64::NAN), None, Some(2.0)]);
+        let values: Vec<_> = F64::iter_arrow(&array).collect();
+        assert_eq!(values, [Some(F64(f64::NAN)), None, Some(F64(2.0))]);
+
+        let array = Float32Array::from(vec![None, Some(-1.0)]);
+        let values: Vec<_> = F32::iter_arrow(&array).collect();
+        assert_eq!(values, [None, Some(F32(-1.0))]);
+    }
+}
@@ -0,0 +1,471 @@
+//! `AtomicF32` and `AtomicF64`, floats that can be shared between threads, stored as their bits
+//! in an `AtomicU32` or `AtomicU64`. They are only available on targets with atomics of that
+//! width.
+//!
+//! Loads, stores and swaps preserve the exact bits. `compare_exchange` compares bitwise, so unlike
+//! `Eq` it tells apart different `NAN`s and the two zeros. The read-modify-write helpers
+//! (`fetch_max_total`, `fetch_min_total`, `fetch_add` and `fetch_update`) are compare-and-swap
+//! loops, and `fetch_max_total` and `fetch_min_total` order values like `Ord` does.
+
+use core::fmt;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::{self, AtomicU32};
+
+use crate::F32;
+#[cfg(target_has_atomic = "64")]
+use crate::F64;
+
+/// Returns the ordering for the loads of a read-modify-write operation with the given ordering,
+/// like the standard atomics use for their `fetch_*` methods.
+fn load_order(order: atomic::Ordering) -> atomic::Ordering {
+    match order {
+        atomic::Ordering::Release => atomic::Ordering::Relaxed,
+        atomic::Ordering::AcqRel => atomic::Ordering::Acquire,
+        order => order,
+    }
+}
+
+/// An `F32` that can be shared between threads, stored as its bits in an `AtomicU32`.
+///
+/// Loads and stores preserve the exact bits, including `NAN` payloads and the sign of zero.
+#[repr(transparent)]
+pub struct AtomicF32(AtomicU32);
+
+impl AtomicF32 {
+    /// Creates a new atomic float.
+    pub const fn new(f: F32) -> Self {
+        AtomicF32(AtomicU32::new(f.0.to_bits()))
+    }
+
+    /// Consumes the atomic and returns the contained value.
+    pub fn into_inner(self) -> F32 {
+        F32(f32::from_bits(self.0.into_inner()))
+    }
+
+    /// Loads the value, see `AtomicU32::load`.
+    pub fn load(&self, order: atomic::Ordering) -> F32 {
+        F32(f32::from_bits(self.0.load(order)))
+    }
+
+    /// Stores a value, see `AtomicU32::store`.
+    pub fn store(&self, f: F32, order: atomic::Ordering) {
+        self.0.store(f.0.to_bits(), order)
+    }
+
+    /// Stores a value and returns the previous one, see `AtomicU32::swap`.
+    pub fn swap(&self, f: F32, order: atomic::Ordering) -> F32 {
+        F32(f32::from_bits(self.0.swap(f.0.to_bits(), order)))
+    }
+
+    /// Replaces the value with `f` applied to it and returns the previous value. `f` may be
+    /// called multiple times if other threads change the value concurrently, see
+    /// `AtomicU32::fetch_update` for the meaning of the orderings.
+    pub fn fetch_update<F: FnMut(F32) -> F32>(
+        &self,
+        set_order: atomic::Ordering,
+        fetch_order: atomic::Ordering,
+        mut f: F,
+    ) -> F32 {
+        let previous = self.0.fetch_update(set_order, fetch_order, |bits| {
+            Some(f(F32(f32::from_bits(bits))).0.to_bits())
+        });
+        // The closure never returns `None`, so this is always `Ok`.
+        match previous {
+            Ok(bits) | Err(bits) => F32(f32::from_bits(bits)),
+        }
+    }
+
+    /// Stores `new` if the current value has the same bits as `current`, see
+    /// `AtomicU32::compare_exchange`. Returns the previous value, in `Ok` if it was replaced.
+    ///
+    /// The comparison is bitwise, not by `Eq`: a `NAN` only matches a `NAN` with the same sign
+    /// and payload, and `0.0` does not match `-0.0`. Pass a value obtained from `load` to
+    /// replace whatever is stored.
+    pub fn compare_exchange(
+        &self,
+        current: F32,
+        new: F32,
+        success: atomic::Ordering,
+        failure: atomic::Ordering,
+    ) -> Result<F32, F32> {
+        match self
+            .0
+            .compare_exchange(current.0.to_bits(), new.0.to_bits(), success, failure)
+        {
+            Ok(bits) => Ok(F32(f32::from_bits(bits))),
+            Err(bits) => Err(F32(f32::from_bits(bits))),
+        }
+    }
+
+    /// Stores the greater of the value and `val` according to `Ord` (so a `NAN` is only stored if
+    /// both are `NAN`), and returns the previous value. Nothing is written if `val` is not
+    /// greater, so the stored bits only change when the value does. `order` works like for
+    /// `AtomicU32::fetch_max`.
+    pub fn fetch_max_total(&self, val: F32, order: atomic::Ordering) -> F32 {
+        self.fetch_update_if(order, |x| if val > x { Some(val) } else { None })
+    }
+
+    /// Stores the lesser of the value and `val` according to `Ord` (so a `NAN` is stored if
+    /// either is `NAN`), and returns the previous value. Nothing is written if `val` is not
+    /// less, so the stored bits only change when the value does. `order` works like for
+    /// `AtomicU32::fetch_min`.
+    pub fn fetch_min_total(&self, val: F32, order: atomic::Ordering) -> F32 {
+        self.fetch_update_if(order, |x| if val < x { Some(val) } else { None })
+    }
+
+    /// Adds `val` to the value and returns the previous value. `order` works like for
+    /// `AtomicU32::fetch_add`.
+    pub fn fetch_add(&self, val: F32, order: atomic::Ordering) -> F32 {
+        self.fetch_update_if(order, |x| Some(x + val))
+    }
+
+    fn fetch_update_if<F: FnMut(F32) -> Option<F32>>(
+        &self,
+        order: atomic::Ordering,
+        mut f: F,
+    ) -> F32 {
+        let previous = self.0.fetch_update(order, load_order(order), |bits| {
+            f(F32(f32::from_bits(bits))).map(|x| x.0.to_bits())
+        });
+        match previous {
+            Ok(bits) | Err(bits) => F32(f32::from_bits(bits)),
+        }
+    }
+}
+
+impl Default for AtomicF32 {
+    fn default() -> Self {
+        AtomicF32::new(F32::default())
+    }
+}
+
+impl From<F32> for AtomicF32 {
+    fn from(f: F32) -> Self {
+        AtomicF32::new(f)
+    }
+}
+
+impl fmt::Debug for AtomicF32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(atomic::Ordering::Relaxed), f)
+    }
+}
+
+/// An `F64` that can be shared between threads, stored as its bits in an `AtomicU64`.
+///
+/// Loads and stores preserve the exact bits, including `NAN` payloads and the sign of zero.
+#[cfg(target_has_atomic = "64")]
+#[repr(transparent)]
+pub struct AtomicF64(AtomicU64);
+
+#[cfg(target_has_atomic = "64")]
+impl AtomicF64 {
+    /// Creates a new atomic float.
+    pub const fn new(f: F64) -> Self {
+        AtomicF64(AtomicU64::new(f.0.to_bits()))
+    }
+
+    /// Consumes the atomic and returns the contained value.
+    pub fn into_inner(self) -> F64 {
+        F64(f64::from_bits(self.0.into_inner()))
+    }
+
+    /// Loads the value, see `AtomicU64::load`.
+    pub fn load(&self, order: atomic::Ordering) -> F64 {
+        F64(f64::from_bits(self.0.load(order)))
+    }
+
+    /// Stores a value, see `AtomicU64::store`.
+    pub fn store(&self, f: F64, order: atomic::Ordering) {
+        self.0.store(f.0.to_bits(), order)
+    }
+
+    /// Stores a value and returns the previous one, see `AtomicU64::swap`.
+    pub fn swap(&self, f: F64, order: atomic::Ordering) -> F64 {
+        F64(f64::from_bits(self.0.swap(f.0.to_bits(), order)))
+    }
+
+    /// Replaces the value with `f` applied to it and returns the previous value. `f` may be
+    /// called multiple times if other threads change the value concurrently, see
+    /// `AtomicU64::fetch_update` for the meaning of the orderings.
+    pub fn fetch_update<F: FnMut(F64) -> F64>(
+        &self,
+        set_order: atomic::Ordering,
+        fetch_order: atomic::Ordering,
+        mut f: F,
+    ) -> F64 {
+        let previous = self.0.fetch_update(set_order, fetch_order, |bits| {
+            Some(f(F64(f64::from_bits(bits))).0.to_bits())
+        });
+        // The closure never returns `None`, so this is always `Ok`.
+        match previous {
+            Ok(bits) | Err(bits) => F64(f64::from_bits(bits)),
+        }
+    }
+
+    /// Stores `new` if the current value has the same bits as `current`, see
+    /// `AtomicU64::compare_exchange`. Returns the previous value, in `Ok` if it was replaced.
+    ///
+    /// The comparison is bitwise, not by `Eq`: a `NAN` only matches a `NAN` with the same sign
+    /// and payload, and `0.0` does not match `-0.0`. Pass a value obtained from `load` to
+    /// replace whatever is stored.
+    pub fn compare_exchange(
+        &self,
+        current: F64,
+        new: F64,
+        success: atomic::Ordering,
+        failure: atomic::Ordering,
+    ) -> Result<F64, F64> {
+        match self
+            .0
+            .compare_exchange(current.0.to_bits(), new.0.to_bits(), success, failure)
+        {
+            Ok(bits) => Ok(F64(f64::from_bits(bits))),
+            Err(bits) => Err(F64(f64::from_bits(bits))),
+        }
+    }
+
+    /// Stores the greater of the value and `val` according to `Ord` (so a `NAN` is only stored if
+    /// both are `NAN`), and returns the previous value. Nothing is written if `val` is not
+    /// greater, so the stored bits only change when the value does. `order` works like for
+    /// `AtomicU64::fetch_max`.
+    pub fn fetch_max_total(&self, val: F64, order: atomic::Ordering) -> F64 {
+        self.fetch_update_if(order, |x| if val > x { Some(val) } else { None })
+    }
+
+    /// Stores the lesser of the value and `val` according to `Ord` (so a `NAN` is stored if
+    /// either is `NAN`), and returns the previous value. Nothing is written if `val` is not
+    /// less, so the stored bits only change when the value does. `order` works like for
+    /// `AtomicU64::fetch_min`.
+    pub fn fetch_min_total(&self, val: F64, order: atomic::Ordering) -> F64 {
+        self.fetch_update_if(order, |x| if val < x { Some(val) } else { None })
+    }
+
+    /// Adds `val` to the value and returns the previous value. `order` works like for
+    /// `AtomicU64::fetch_add`.
+    pub fn fetch_add(&self, val: F64, order: atomic::Ordering) -> F64 {
+        self.fetch_update_if(order, |x| Some(x + val))
+    }
+
+    fn fetch_update_if<F: FnMut(F64) -> Option<F64>>(
+        &self,
+        order: atomic::Ordering,
+        mut f: F,
+    ) -> F64 {
+        let previous = self.0.fetch_update(order, load_order(order), |bits| {
+            f(F64(f64::from_bits(bits))).map(|x| x.0.to_bits())
+        });
+        match previous {
+            Ok(bits) | Err(bits) => F64(f64::from_bits(bits)),
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Default for AtomicF64 {
+    fn default() -> Self {
+        AtomicF64::new(F64::default())
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl From<F64> for AtomicF64 {
+    fn from(f: F64) -> Self {
+        AtomicF64::new(f)
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl fmt::Debug for AtomicF64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(atomic::Ordering::Relaxed), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::Ordering::{AcqRel, Relaxed, Release, SeqCst};
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn f32_bits() {
+        let nan = F32(f32::from_bits(0xffc0_1234));
+        let atomic = AtomicF32::new(nan);
+        assert_eq!(atomic.load(SeqCst).0.to_bits(), 0xffc0_1234);
+        assert_eq!(atomic.swap(F32(-0.0), SeqCst).0.to_bits(), 0xffc0_1234);
+        assert_eq!(atomic.into_inner().0.to_bits(), 0x8000_0000);
+    }
+
+    #[test]
+    fn f32_fetch_update() {
+        let atomic = AtomicF32::new(F32(0.0));
+        thread::scope(|s| {
+            for _ in 0..8 {
+                s.spawn(|| {
+                    for _ in 0..1000 {
+                        atomic.fetch_update(SeqCst, SeqCst, |x| x + F32(1.0));
+                    }
+                });
+            }
+        });
+        assert_eq!(atomic.load(SeqCst), F32(8000.0));
+    }
+
+    #[test]
+    fn f32_compare_exchange() {
+        let atomic = AtomicF32::new(F32(-0.0));
+        assert_eq!(
+            atomic
+                .compare_exchange(F32(0.0), F32(1.0), SeqCst, SeqCst)
+                .map_err(|x| x.0.to_bits()),
+            Err(0x8000_0000)
+        );
+        assert_eq!(
+            atomic.compare_exchange(F32(-0.0), F32(f32::NAN), SeqCst, SeqCst),
+            Ok(F32(0.0))
+        );
+        assert!(atomic
+            .compare_exchange(F32(-f32::NAN), F32(1.0), SeqCst, SeqCst)
+            .is_err());
+        let current = atomic.load(SeqCst);
+        assert!(atomic
+            .compare_exchange(current, F32(1.0), SeqCst, SeqCst)
+            .is_ok());
+        assert_eq!(atomic.load(SeqCst), F32(1.0));
+    }
+
+    #[test]
+    fn f32_fetch_min_max_add() {
+        let atomic = AtomicF32::new(F32(1.0));
+        assert_eq!(atomic.fetch_max_total(F32(2.0), SeqCst), F32(1.0));
+        assert_eq!(atomic.fetch_max_total(F32(f32::NAN), SeqCst), F32(2.0));
+        assert_eq!(atomic.fetch_min_total(F32(-1.0), AcqRel), F32(2.0));
+        assert_eq!(atomic.fetch_add(F32(0.5), Release), F32(-1.0));
+        assert_eq!(atomic.fetch_min_total(F32(f32::NAN), Relaxed), F32(-0.5));
+        assert!(atomic.load(SeqCst).0.is_nan());
+        assert!(atomic.fetch_add(F32(1.0), SeqCst).0.is_nan());
+    }
+
+    #[test]
+    fn f32_fetch_max_total_threads() {
+        let mut values = [
+            F32(f32::NAN),
+            F32(-0.0),
+            F32(0.0),
+            F32(-f32::NAN),
+            F32(f32::NEG_INFINITY),
+        ]
+        .to_vec();
+        values.extend((0..4000).map(|i| F32((i % 997) as f32 * -1.5)));
+        let expected = values.iter().copied().max().unwrap();
+
+        let atomic = AtomicF32::new(F32(f32::NAN));
+        thread::scope(|s| {
+            for chunk in values.chunks(500) {
+                let atomic = &atomic;
+                s.spawn(move || {
+                    for &x in chunk {
+                        atomic.fetch_max_total(x, AcqRel);
+                    }
+                });
+            }
+        });
+        assert_eq!(atomic.load(SeqCst), expected);
+        assert_eq!(expected, F32(0.0));
+    }
+
+    #[test]
+    fn f64_bits() {
+        let nan = F64(f64::from_bits(0xfff8_0000_0000_1234));
+        let atomic = AtomicF64::new(nan);
+        assert_eq!(atomic.load(SeqCst).0.to_bits(), 0xfff8_0000_0000_1234);
+        atomic.store(F64(-0.0), SeqCst);
+        assert_eq!(atomic.into_inner().0.to_bits(), 0x8000_0000_0000_0000);
+    }
+
+    #[test]
+    fn f64_fetch_update() {
+        let atomic = AtomicF64::new(F64(0.5));
+        thread::scope(|s| {
+            for _ in 0..8 {
+                s.spawn(|| {
+                    for _ in 0..1000 {
+                        atomic.fetch_update(SeqCst, SeqCst, |x| x + F64(1.0));
+                    }
+                });
+            }
+        });
+        assert_eq!(atomic.load(SeqCst), F64(8000.5));
+        assert_eq!(
+            atomic.fetch_update(SeqCst, SeqCst, |x| x * F64(2.0)),
+            F64(8000.5)
+        );
+        assert_eq!(atomic.load(SeqCst), F64(16001.0));
+    }
+
+    #[test]
+    fn f64_compare_exchange() {
+        let atomic = AtomicF64::new(F64(-0.0));
+        assert_eq!(
+            atomic
+                .compare_exchange(F64(0.0), F64(1.0), SeqCst, SeqCst)
+                .map_err(|x| x.0.to_bits()),
+            Err(0x8000_0000_0000_0000)
+        );
+        assert_eq!(
+            atomic.compare_exchange(F64(-0.0), F64(f64::NAN), SeqCst, SeqCst),
+            Ok(F64(0.0))
+        );
+        assert!(atomic
+            .compare_exchange(F64(-f64::NAN), F64(1.0), SeqCst, SeqCst)
+            .is_err());
+        let current = atomic.load(SeqCst);
+        assert!(atomic
+            .compare_exchange(current, F64(1.0), SeqCst, SeqCst)
+            .is_ok());
+        assert_eq!(atomic.load(SeqCst), F64(1.0));
+    }
+
+    #[test]
+    fn f64_fetch_min_max_add() {
+        let atomic = AtomicF64::new(F64(1.0));
+        assert_eq!(atomic.fetch_max_total(F64(2.0), SeqCst), F64(1.0));
+        assert_eq!(atomic.fetch_max_total(F64(f64::NAN), SeqCst), F64(2.0));
+        assert_eq!(atomic.fetch_min_total(F64(-1.0), AcqRel), F64(2.0));
+        assert_eq!(atomic.fetch_add(F64(0.5), Release), F64(-1.0));
+        assert_eq!(atomic.fetch_min_total(F64(f64::NAN), Relaxed), F64(-0.5));
+        assert!(atomic.load(SeqCst).0.is_nan());
+        assert!(atomic.fetch_add(F64(1.0), SeqCst).0.is_nan());
+    }
+
+    #[test]
+    fn f64_fetch_max_total_threads() {
+        let mut values = [
+            F64(f64::NAN),
+            F64(-0.0),
+            F64(0.0),
+            F64(-f64::NAN),
+            F64(f64::NEG_INFINITY),
+        ]
+        .to_vec();
+        values.extend((0..4000).map(|i| F64((i % 997) as f64 * -1.5)));
+        let expected = values.iter().copied().max().unwrap();
+
+        let atomic = AtomicF64::new(F64(f64::NAN));
+        thread::scope(|s| {
+            for chunk in values.chunks(500) {
+                let atomic = &atomic;
+                s.spawn(move || {
+                    for &x in chunk {
+                        atomic.fetch_max_total(x, AcqRel);
+                    }
+                });
+            }
+        });
+        assert_eq!(atomic.load(SeqCst), expected);
+        assert_eq!(expected, F64(0.0));
+    }
+}
@@ -0,0 +1,209 @@
+//! Wrappers whose `Eq` and `Hash` compare the bits of the float, for cache keys and interning.
+//!
+//! Unlike with `F32` and `F64`, `-0.0` and `0.0` are distinct, and so are `NAN`s with different
+//! bits.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::{TotalF32, TotalF64, F32, F64};
+
+/// A wrapper around `f32` whose comparisons and hashing operate on `f32::to_bits`.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct BitEq32(pub f32);
+
+/// Two values are equal if and only if they have the same bits.
+impl PartialEq for BitEq32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for BitEq32 {}
+
+/// This always returns a `Some`.
+impl PartialOrd for BitEq32 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the bits as unsigned integers. This is a total order consistent with `Eq`, but
+/// otherwise arbitrary: it has little to do with the numeric order (e.g. all negative values are
+/// greater than all positive values).
+impl Ord for BitEq32 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.to_bits().cmp(&other.0.to_bits())
+    }
+}
+
+impl Hash for BitEq32 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl From<BitEq32> for f32 {
+    fn from(f: BitEq32) -> Self {
+        f.0
+    }
+}
+
+impl From<f32> for BitEq32 {
+    fn from(f: f32) -> Self {
+        BitEq32(f)
+    }
+}
+
+impl From<BitEq32> for F32 {
+    fn from(f: BitEq32) -> Self {
+        F32(f.0)
+    }
+}
+
+impl From<F32> for BitEq32 {
+    fn from(f: F32) -> Self {
+        BitEq32(f.0)
+    }
+}
+
+impl From<BitEq32> for TotalF32 {
+    fn from(f: BitEq32) -> Self {
+        TotalF32(f.0)
+    }
+}
+
+impl From<TotalF32> for BitEq32 {
+    fn from(f: TotalF32) -> Self {
+        BitEq32(f.0)
+    }
+}
+
+impl fmt::Display for BitEq32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A wrapper around `f64` whose comparisons and hashing operate on `f64::to_bits`.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct BitEq64(pub f64);
+
+/// Two values are equal if and only if they have the same bits.
+impl PartialEq for BitEq64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for BitEq64 {}
+
+/// This always returns a `Some`.
+impl PartialOrd for BitEq64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the bits as unsigned integers. This is a total order consistent with `Eq`, but
+/// otherwise arbitrary: it has little to do with the numeric order (e.g. all negative values are
+/// greater than all positive values).
+impl Ord for BitEq64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.to_bits().cmp(&other.0.to_bits())
+    }
+}
+
+impl Hash for BitEq64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl From<BitEq64> for f64 {
+    fn from(f: BitEq64) -> Self {
+        f.0
+    }
+}
+
+impl From<f64> for BitEq64 {
+    fn from(f: f64) -> Self {
+        BitEq64(f)
+    }
+}
+
+impl From<BitEq64> for F64 {
+    fn from(f: BitEq64) -> Self {
+        F64(f.0)
+    }
+}
+
+impl From<F64> for BitEq64 {
+    fn from(f: F64) -> Self {
+        BitEq64(f.0)
+    }
+}
+
+impl From<BitEq64> for TotalF64 {
+    fn from(f: BitEq64) -> Self {
+        TotalF64(f.0)
+    }
+}
+
+impl From<TotalF64> for BitEq64 {
+    fn from(f: TotalF64) -> Self {
+        BitEq64(f.0)
+    }
+}
+
+impl fmt::Display for BitEq64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{BitEq32, BitEq64};
+
+    #[test]
+    fn bit_eq32() {
+        let payload = f32::from_bits(f32::NAN.to_bits() | 1);
+        let mut set = HashSet::new();
+        assert!(set.insert(BitEq32(0.0)));
+        assert!(set.insert(BitEq32(-0.0)));
+        assert!(set.insert(BitEq32(f32::NAN)));
+        assert!(set.insert(BitEq32(payload)));
+        assert!(!set.insert(BitEq32(f32::NAN)));
+        assert!(!set.insert(BitEq32(0.0)));
+        assert_eq!(set.len(), 4);
+
+        assert!(BitEq32(f32::NAN) == BitEq32(f32::NAN));
+        assert!(BitEq32(f32::NAN) != BitEq32(payload));
+        assert!(BitEq32(1.0) < BitEq32(2.0));
+        assert!(BitEq32(-1.0) > BitEq32(1.0));
+    }
+
+    #[test]
+    fn bit_eq64() {
+        let payload = f64::from_bits(f64::NAN.to_bits() | 1);
+        let mut set = HashSet::new();
+        assert!(set.insert(BitEq64(0.0)));
+        assert!(set.insert(BitEq64(-0.0)));
+        assert!(set.insert(BitEq64(f64::NAN)));
+        assert!(set.insert(BitEq64(payload)));
+        assert!(!set.insert(BitEq64(f64::NAN)));
+        assert!(!set.insert(BitEq64(0.0)));
+        assert_eq!(set.len(), 4);
+
+        assert!(BitEq64(f64::NAN) == BitEq64(f64::NAN));
+        assert!(BitEq64(f64::NAN) != BitEq64(payload));
+        assert!(BitEq64(1.0) < BitEq64(2.0));
+        assert!(BitEq64(-1.0) > BitEq64(1.0));
+    }
+}
@@ -0,0 +1,55 @@
+//! Range queries on a `BTreeMap` keyed by `F64`.
+//!
+//! Since `NAN` is the least `F64` and all `NAN`s are equal, a map holds at most one `NAN` key,
+//! and it is the first key of the map.
+
+use alloc::collections::btree_map::{BTreeMap, Range};
+use core::ops::Bound::Excluded;
+
+use crate::F64;
+
+/// Returns the entries with finite keys, in ascending order. This skips the `NAN` entry and the
+/// entries keyed by the infinities.
+pub fn range_finite<V>(map: &BTreeMap<F64, V>) -> Range<'_, F64, V> {
+    map.range((Excluded(F64::NEG_INFINITY), Excluded(F64::INFINITY)))
+}
+
+/// Returns the entry keyed by `NAN`, if any.
+pub fn nan_entries<V>(map: &BTreeMap<F64, V>) -> Option<(&F64, &V)> {
+    map.get_key_value(&F64::NAN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map() -> BTreeMap<F64, &'static str> {
+        let mut map = BTreeMap::new();
+        map.insert(F64(2.0), "two");
+        map.insert(F64(f64::INFINITY), "inf");
+        map.insert(F64(f64::NAN), "nan");
+        map.insert(F64(-0.0), "zero");
+        map.insert(F64(f64::NEG_INFINITY), "-inf");
+        map.insert(F64(-f64::NAN), "-nan");
+        map.insert(F64(-1.5), "-1.5");
+        map
+    }
+
+    #[test]
+    fn finite() {
+        let map = map();
+        let entries: Vec<_> = range_finite(&map).map(|(k, v)| (k.0, *v)).collect();
+        assert_eq!(entries, vec![(-1.5, "-1.5"), (-0.0, "zero"), (2.0, "two")]);
+        assert_eq!(range_finite(&BTreeMap::<F64, ()>::new()).count(), 0);
+    }
+
+    #[test]
+    fn nan() {
+        let map = map();
+        let (key, value) = nan_entries(&map).unwrap();
+        assert!(key.0.is_nan());
+        assert_eq!(*value, "-nan");
+        assert_eq!(map.keys().next(), Some(&F64::NAN));
+        assert_eq!(nan_entries(&BTreeMap::<F64, ()>::new()), None);
+    }
+}
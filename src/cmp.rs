@@ -0,0 +1,99 @@
+//! Free functions that compare the primitive floats like the wrappers do, for places that take a
+//! comparison function such as `sort_by`. They are `const`.
+
+use core::cmp::Ordering;
+
+use crate::{F32, F64};
+
+/// Compares like `Ord` on `F32` does, for use where a comparison function on the primitives is
+/// needed, e.g. `v.sort_by(|a, b| eq_float::total_cmp_f32(*a, *b))`.
+#[inline]
+pub const fn total_cmp_f32(a: f32, b: f32) -> Ordering {
+    F32::const_cmp(F32(a), F32(b))
+}
+
+/// Compares like `Eq` on `F32` does.
+#[inline]
+pub const fn total_eq_f32(a: f32, b: f32) -> bool {
+    F32::const_eq(F32(a), F32(b))
+}
+
+/// Compares like `Ord` on `F64` does, for use where a comparison function on the primitives is
+/// needed, e.g. `v.sort_by(|a, b| eq_float::total_cmp_f64(*a, *b))`.
+#[inline]
+pub const fn total_cmp_f64(a: f64, b: f64) -> Ordering {
+    F64::const_cmp(F64(a), F64(b))
+}
+
+/// Compares like `Eq` on `F64` does.
+#[inline]
+pub const fn total_eq_f64(a: f64, b: f64) -> bool {
+    F64::const_eq(F64(a), F64(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f32_agrees() {
+        let special = [
+            f32::NAN,
+            -f32::NAN,
+            f32::NEG_INFINITY,
+            f32::MIN,
+            -1.0,
+            -f32::MIN_POSITIVE,
+            -f32::from_bits(1),
+            -0.0,
+            0.0,
+            f32::from_bits(1),
+            f32::MIN_POSITIVE,
+            1.0,
+            f32::MAX,
+            f32::INFINITY,
+        ];
+        for &a in &special {
+            for &b in &special {
+                assert_eq!(total_cmp_f32(a, b), F32(a).cmp(&F32(b)));
+                assert_eq!(total_eq_f32(a, b), F32(a) == F32(b));
+            }
+        }
+
+        let mut v = [2.0, f32::NAN, -1.0, f32::INFINITY];
+        v.sort_by(|a, b| total_cmp_f32(*a, *b));
+        assert!(v[0].is_nan());
+        assert_eq!(v[1..], [-1.0, 2.0, f32::INFINITY]);
+    }
+
+    #[test]
+    fn f64_agrees() {
+        let special = [
+            f64::NAN,
+            -f64::NAN,
+            f64::NEG_INFINITY,
+            f64::MIN,
+            -1.0,
+            -f64::MIN_POSITIVE,
+            -f64::from_bits(1),
+            -0.0,
+            0.0,
+            f64::from_bits(1),
+            f64::MIN_POSITIVE,
+            1.0,
+            f64::MAX,
+            f64::INFINITY,
+        ];
+        for &a in &special {
+            for &b in &special {
+                assert_eq!(total_cmp_f64(a, b), F64(a).cmp(&F64(b)));
+                assert_eq!(total_eq_f64(a, b), F64(a) == F64(b));
+            }
+        }
+
+        let mut v = [2.0, f64::NAN, -1.0, f64::INFINITY];
+        v.sort_by(|a, b| total_cmp_f64(*a, *b));
+        assert!(v[0].is_nan());
+        assert_eq!(v[1..], [-1.0, 2.0, f64::INFINITY]);
+    }
+}
@@ -0,0 +1,126 @@
+//! Conversions between `F32`/`F64` and `rust_decimal::Decimal`.
+//!
+//! Converting a float to a `Decimal` fails for `NAN`, the infinities, and values whose magnitude
+//! is too large for a `Decimal`. Converting a `Decimal` to a float always succeeds, yielding the
+//! nearest representable float.
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use crate::{F32, F64};
+
+/// The error returned when converting an `F32` or an `F64` to a `Decimal` fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecimalError {
+    /// The float is `NAN`.
+    Nan,
+    /// The float is positive or negative infinity.
+    Infinite,
+    /// The float is finite, but its magnitude is too large for a `Decimal`.
+    OutOfRange,
+}
+
+impl fmt::Display for DecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecimalError::Nan => write!(f, "can not convert NAN to a decimal"),
+            DecimalError::Infinite => write!(f, "can not convert an infinite float to a decimal"),
+            DecimalError::OutOfRange => write!(f, "float is out of the range of decimals"),
+        }
+    }
+}
+
+impl std::error::Error for DecimalError {}
+
+impl TryFrom<F32> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(f: F32) -> Result<Self, Self::Error> {
+        if f.0.is_nan() {
+            Err(DecimalError::Nan)
+        } else if f.0.is_infinite() {
+            Err(DecimalError::Infinite)
+        } else {
+            Decimal::try_from(f.0).map_err(|_| DecimalError::OutOfRange)
+        }
+    }
+}
+
+impl From<Decimal> for F32 {
+    fn from(d: Decimal) -> Self {
+        F32(d.to_f32().expect("every decimal is in the range of f32"))
+    }
+}
+
+impl TryFrom<F64> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(f: F64) -> Result<Self, Self::Error> {
+        if f.0.is_nan() {
+            Err(DecimalError::Nan)
+        } else if f.0.is_infinite() {
+            Err(DecimalError::Infinite)
+        } else {
+            Decimal::try_from(f.0).map_err(|_| DecimalError::OutOfRange)
+        }
+    }
+}
+
+impl From<Decimal> for F64 {
+    fn from(d: Decimal) -> Self {
+        F64(d.to_f64().expect("every decimal is in the range of f64"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::DecimalError;
+    use crate::{F32, F64};
+
+    #[test]
+    fn round_trip() {
+        for f in [0.0, -2.5, 1234.125, 0.75] {
+            let d = Decimal::try_from(F64(f)).unwrap();
+            assert_eq!(F64::from(d), F64(f));
+
+            let d = Decimal::try_from(F32(f as f32)).unwrap();
+            assert_eq!(F32::from(d), F32(f as f32));
+        }
+
+        assert_eq!(
+            Decimal::try_from(F64(-2.5)).unwrap(),
+            Decimal::from_str("-2.5").unwrap()
+        );
+        assert_eq!(F64::from(Decimal::from_str("0.1").unwrap()), F64(0.1));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Decimal::try_from(F64(f64::NAN)), Err(DecimalError::Nan));
+        assert_eq!(
+            Decimal::try_from(F64(f64::INFINITY)),
+            Err(DecimalError::Infinite)
+        );
+        assert_eq!(
+            Decimal::try_from(F64(-1e300)),
+            Err(DecimalError::OutOfRange)
+        );
+        assert_eq!(Decimal::try_from(F32(-f32::NAN)), Err(DecimalError::Nan));
+        assert_eq!(
+            Decimal::try_from(F32(f32::NEG_INFINITY)),
+            Err(DecimalError::Infinite)
+        );
+        assert_eq!(
+            Decimal::try_from(F32(f32::MAX)),
+            Err(DecimalError::OutOfRange)
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Conversions between `F32`/`F64` and `Total` of the `decorum` crate. A conversion never changes
+//! the bits of the float, only the wrapper, and never fails, since `Total` admits every value.
+//!
+//! The orderings agree on everything but `NAN`:
+//!
+//! | | `F32`/`F64` | `decorum::Total` |
+//! |-|-|-|
+//! | `NAN` | less than all other values | greater than all other values |
+//! | `NAN == NAN` | `true` | `true` |
+//! | `-0.0 == 0.0` | `true` | `true` |
+
+use decorum::Total;
+
+use crate::{F32, F64};
+
+impl From<Total<f32>> for F32 {
+    fn from(f: Total<f32>) -> Self {
+        F32(f.into_inner())
+    }
+}
+
+/// Note that `Total` sorts `NAN` above all other values, whereas `F32` sorts it below.
+impl From<F32> for Total<f32> {
+    fn from(f: F32) -> Self {
+        Total::from_inner(f.0)
+    }
+}
+
+impl From<Total<f64>> for F64 {
+    fn from(f: Total<f64>) -> Self {
+        F64(f.into_inner())
+    }
+}
+
+/// Note that `Total` sorts `NAN` above all other values, whereas `F64` sorts it below.
+impl From<F64> for Total<f64> {
+    fn from(f: F64) -> Self {
+        Total::from_inner(f.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::Total;
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn total_round_trip() {
+        for bits in [1.5f32.to_bits(), f32::NAN.to_bits(), (-0.0f32).to_bits()] {
+            let f = F32(f32::from_bits(bits));
+            let total = Total::from(f);
+            assert_eq!(total.into_inner().to_bits(), bits);
+            assert_eq!(F32::from(total).0.to_bits(), bits);
+        }
+
+        for bits in [1.5f64.to_bits(), f64::NAN.to_bits(), (-0.0f64).to_bits()] {
+            let f = F64(f64::from_bits(bits));
+            let total = Total::from(f);
+            assert_eq!(total.into_inner().to_bits(), bits);
+            assert_eq!(F64::from(total).0.to_bits(), bits);
+        }
+    }
+
+    #[test]
+    fn total_nan_position() {
+        assert!(F64(f64::NAN) < F64(1.0));
+        assert!(Total::from(F64(f64::NAN)) > Total::from(F64(1.0)));
+        assert!(F32(f32::NAN) < F32(1.0));
+        assert!(Total::from(F32(f32::NAN)) > Total::from(F32(1.0)));
+    }
+}
@@ -0,0 +1,40 @@
+//! `defmt` support. Every wrapper is formatted exactly like its inner float, so logging a wrapped
+//! value costs no more than logging the primitive.
+
+use defmt::{Format, Formatter};
+
+use crate::{NanHigh32, NanHigh64, TotalF32, TotalF64, F32, F64};
+
+macro_rules! impl_format {
+    ($($ty:ty),*) => {
+        $(
+            impl Format for $ty {
+                #[inline]
+                fn format(&self, f: Formatter) {
+                    self.0.format(f)
+                }
+            }
+        )*
+    };
+}
+
+impl_format!(F32, F64, NanHigh32, NanHigh64, TotalF32, TotalF64);
+
+#[cfg(test)]
+mod tests {
+    use defmt::Format;
+
+    use crate::{NanHigh32, NanHigh64, TotalF32, TotalF64, F32, F64};
+
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn implements_format() {
+        assert_format::<F32>();
+        assert_format::<F64>();
+        assert_format::<NanHigh32>();
+        assert_format::<NanHigh64>();
+        assert_format::<TotalF32>();
+        assert_format::<TotalF64>();
+    }
+}
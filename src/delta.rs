@@ -0,0 +1,69 @@
+//! Delta encoding of `F64` sequences over the order-preserving `to_ordered_bits` keys, for
+//! compressing sorted time series. Requires the `alloc` feature.
+
+use alloc::vec::Vec;
+
+use crate::F64;
+
+/// Encodes the values as the successive differences of their `F64::to_ordered_bits` keys, the
+/// first value as the difference from `0`. The differences wrap around, so any slice can be
+/// encoded, but for a sorted slice they are the distances between neighbouring values, which are
+/// small for nearby values and hence compress well.
+///
+/// `delta_decode` reverses the encoding, except that all `NAN`s decode to `f64::NAN` and both
+/// zeros to `0.0`, since `to_ordered_bits` does not distinguish them.
+pub fn delta_encode(values: &[F64]) -> Vec<u64> {
+    let mut previous = 0;
+    values
+        .iter()
+        .map(|x| {
+            let key = x.to_ordered_bits();
+            let delta = key.wrapping_sub(previous);
+            previous = key;
+            delta
+        })
+        .collect()
+}
+
+/// Decodes the output of `delta_encode`.
+pub fn delta_decode(deltas: &[u64]) -> Vec<F64> {
+    let mut key = 0u64;
+    deltas
+        .iter()
+        .map(|delta| {
+            key = key.wrapping_add(*delta);
+            F64::from_ordered_bits(key)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let values = [
+            F64(f64::NAN),
+            F64(f64::NEG_INFINITY),
+            F64(-1.0),
+            F64(-0.0),
+            F64(5e-324),
+            F64(1.0),
+            F64(1.0 + f64::EPSILON),
+            F64(f64::MAX),
+            F64(f64::INFINITY),
+        ];
+        let deltas = delta_encode(&values);
+        assert_eq!(deltas[0], 0);
+        assert_eq!(deltas[4], 1);
+        assert_eq!(deltas[6], 1);
+        assert_eq!(delta_decode(&deltas), values);
+        assert_eq!(delta_decode(&deltas)[3].0.to_bits(), 0);
+
+        let unsorted = [F64(3.0), F64(-2.0), F64(f64::NAN), F64(0.5)];
+        assert_eq!(delta_decode(&delta_encode(&unsorted)), unsorted);
+        assert!(delta_encode(&[]).is_empty());
+        assert!(delta_decode(&[]).is_empty());
+    }
+}
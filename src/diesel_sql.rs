@@ -0,0 +1,51 @@
+//! `diesel` support. `F32` maps to the `Float` SQL type and `F64` to the `Double` SQL type,
+//! exactly like `f32` and `f64` do, on every backend that supports those primitives.
+//!
+//! The module is not called `diesel`, since the diesel derives on `F32` and `F64` in the crate
+//! root refer to the `diesel` crate by its bare name.
+//!
+//! Note that SQLite stores `NAN` as `NULL`, so a `NAN` does not survive a round trip there,
+//! whereas it does in Postgres.
+
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{Double, Float};
+
+use crate::{F32, F64};
+
+impl<DB: Backend> ToSql<Float, DB> for F32
+where
+    f32: ToSql<Float, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <f32 as ToSql<Float, DB>>::to_sql(&self.0, out)
+    }
+}
+
+impl<DB: Backend> FromSql<Float, DB> for F32
+where
+    f32: FromSql<Float, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        <f32 as FromSql<Float, DB>>::from_sql(bytes).map(F32)
+    }
+}
+
+impl<DB: Backend> ToSql<Double, DB> for F64
+where
+    f64: ToSql<Double, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <f64 as ToSql<Double, DB>>::to_sql(&self.0, out)
+    }
+}
+
+impl<DB: Backend> FromSql<Double, DB> for F64
+where
+    f64: FromSql<Double, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        <f64 as FromSql<Double, DB>>::from_sql(bytes).map(F64)
+    }
+}
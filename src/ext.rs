@@ -0,0 +1,133 @@
+//! Extension traits that bring the wrappers' semantics to the primitives: `IntoEqFloat` wraps a
+//! float with a method call, and `EqFloatExt` compares primitives like the wrappers do.
+
+use core::cmp::Ordering;
+
+use crate::{F32, F64};
+
+/// Wraps a primitive float as a method call, e.g. `1.5f64.eq_float()`. This works exactly like
+/// the `From` impls. `F32` and `F64` implement it too, returning themselves, so generic code can
+/// accept either a primitive or a wrapper.
+pub trait IntoEqFloat {
+    /// The wrapper type.
+    type Output;
+
+    /// Wraps the float.
+    fn eq_float(self) -> Self::Output;
+}
+
+impl IntoEqFloat for f32 {
+    type Output = F32;
+
+    fn eq_float(self) -> F32 {
+        F32(self)
+    }
+}
+
+impl IntoEqFloat for f64 {
+    type Output = F64;
+
+    fn eq_float(self) -> F64 {
+        F64(self)
+    }
+}
+
+impl IntoEqFloat for F32 {
+    type Output = F32;
+
+    fn eq_float(self) -> F32 {
+        self
+    }
+}
+
+impl IntoEqFloat for F64 {
+    type Output = F64;
+
+    fn eq_float(self) -> F64 {
+        self
+    }
+}
+
+/// Methods applying the semantics of `F32` and `F64` to the primitives: all `NAN`s are equal
+/// and less than all other values, and the two zeros are equal.
+///
+/// Note that this is not the IEEE 754 `totalOrder` of `f32::total_cmp` and `TotalF32`.
+pub trait EqFloatExt: IntoEqFloat {
+    /// Wraps the float, like `IntoEqFloat::eq_float`.
+    fn total(self) -> Self::Output;
+
+    /// Compares like `Ord` on the wrapper does.
+    fn total_cmp_to(self, other: Self) -> Ordering;
+
+    /// Compares like `Eq` on the wrapper does.
+    fn total_eq(self, other: Self) -> bool;
+}
+
+impl EqFloatExt for f32 {
+    fn total(self) -> F32 {
+        F32(self)
+    }
+
+    fn total_cmp_to(self, other: Self) -> Ordering {
+        F32::const_cmp(F32(self), F32(other))
+    }
+
+    fn total_eq(self, other: Self) -> bool {
+        F32::const_eq(F32(self), F32(other))
+    }
+}
+
+impl EqFloatExt for f64 {
+    fn total(self) -> F64 {
+        F64(self)
+    }
+
+    fn total_cmp_to(self, other: Self) -> Ordering {
+        F64::const_cmp(F64(self), F64(other))
+    }
+
+    fn total_eq(self, other: Self) -> bool {
+        F64::const_eq(F64(self), F64(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn eq_float() {
+        assert_eq!(1.5f32.eq_float(), F32(1.5));
+        assert_eq!(f64::NAN.eq_float(), F64(f64::NAN));
+
+        let max = [1.0, f64::NAN, 3.5, -2.0]
+            .iter()
+            .map(|x| x.eq_float())
+            .max();
+        assert_eq!(max, Some(F64(3.5)));
+    }
+
+    #[test]
+    fn total() {
+        let v = [2.0f32, f32::NAN, -0.0, 0.0, -f32::NAN, 1.0];
+        let set = v.iter().copied().map(f32::total).collect::<BTreeSet<_>>();
+        assert_eq!(set.len(), 4);
+        assert!(set.iter().next().unwrap().0.is_nan());
+        assert_eq!(set.iter().nth(1), Some(&F32(0.0)));
+
+        assert_eq!(1.5f64.total(), F64(1.5));
+    }
+
+    #[test]
+    fn total_cmp() {
+        assert!(f64::NAN.total_eq(-f64::NAN));
+        assert!(!f64::NAN.total_eq(1.0));
+        assert!(0.0f32.total_eq(-0.0));
+        assert_eq!(f32::NAN.total_cmp_to(f32::NEG_INFINITY), Ordering::Less);
+        assert_eq!(1.0f64.total_cmp_to(f64::NAN), Ordering::Greater);
+        assert_eq!((-0.0f64).total_cmp_to(0.0), Ordering::Equal);
+        assert_eq!(1.0f32.total_cmp_to(2.0), Ordering::Less);
+    }
+}
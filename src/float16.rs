@@ -0,0 +1,130 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use half::f16;
+
+use crate::{CanonicalBits, EqFloat, F32, F64};
+
+/// A wrapper around `half::f16` with the same semantics as `F32` and `F64`.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct F16(pub f16);
+
+/// This works like `PartialEq` on `f16`, except that `NAN == NAN` is true.
+impl PartialEq for F16 {
+    fn eq(&self, other: &Self) -> bool {
+        EqFloat(self.0) == EqFloat(other.0)
+    }
+}
+
+impl Eq for F16 {}
+
+/// This works like `PartialOrd` on `f16`, except that `NAN` sorts below all other floats
+/// (and is equal to another NAN). This always returns a `Some`.
+impl PartialOrd for F16 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// This works like `PartialOrd` on `f16`, except that `NAN` sorts below all other floats
+/// (and is equal to another NAN).
+impl Ord for F16 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        EqFloat(self.0).cmp(&EqFloat(other.0))
+    }
+}
+
+impl Hash for F16 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        EqFloat(self.0).hash(state);
+    }
+}
+
+impl CanonicalBits for f16 {
+    type Bits = u16;
+
+    fn is_nan(self) -> bool {
+        f16::is_nan(self)
+    }
+
+    fn canonical_bits(self) -> u16 {
+        if self.is_nan() {
+            0x7e00 // a particular bit representation for NAN
+        } else if self == f16::ZERO { // catches both positive and negative zero
+            0
+        } else {
+            self.to_bits()
+        }
+    }
+}
+
+impl From<F16> for f16 {
+    fn from(f: F16) -> Self {
+        f.0
+    }
+}
+
+impl From<f16> for F16 {
+    fn from(f: f16) -> Self {
+        F16(f)
+    }
+}
+
+/// Widens losslessly, `NAN` stays `NAN`.
+impl From<F16> for F32 {
+    fn from(f: F16) -> Self {
+        F32(f.0.to_f32())
+    }
+}
+
+/// Widens losslessly, `NAN` stays `NAN`.
+impl From<F16> for F64 {
+    fn from(f: F16) -> Self {
+        F64(f.0.to_f64())
+    }
+}
+
+impl fmt::Display for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use half::f16;
+
+    use super::F16;
+    use crate::{EqFloat, F32, F64};
+
+    fn calculate_hash<T: Hash>(t: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+
+    fn h(f: f32) -> F16 {
+        F16(f16::from_f32(f))
+    }
+
+    #[test]
+    fn f16_generic() {
+        assert!(EqFloat(f16::NAN) == EqFloat(-f16::NAN));
+        assert!(EqFloat(f16::NAN) < EqFloat(f16::NEG_INFINITY));
+        assert!(calculate_hash(&EqFloat(f16::NEG_ZERO)) == calculate_hash(&F16(f16::ZERO)));
+    }
+
+    #[test]
+    fn f16_widening() {
+        assert_eq!(F32::from(h(1.5)), F32(1.5));
+        assert_eq!(F64::from(h(-0.25)), F64(-0.25));
+        assert_eq!(F32::from(F16(f16::NAN)), F32(f32::NAN));
+        assert_eq!(F64::from(F16(-f16::NAN)), F64(f64::NAN));
+        assert!(F32::from(F16(f16::NEG_ZERO)).0.is_sign_negative());
+    }
+}
@@ -0,0 +1,180 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::{F32, F64};
+
+/// The floats that can be wrapped in an `EqFloat`.
+pub trait CanonicalBits: Copy + PartialOrd {
+    /// The type of the bit representation of the float.
+    type Bits: Hash;
+
+    /// Returns whether the float is `NAN`.
+    fn is_nan(self) -> bool;
+
+    /// Returns the bits of the float, except that all `NAN`s map to the same bits, and so do
+    /// both zeros.
+    fn canonical_bits(self) -> Self::Bits;
+}
+
+impl CanonicalBits for f32 {
+    type Bits = u32;
+
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+
+    fn canonical_bits(self) -> u32 {
+        F32(self).canonical_bits()
+    }
+}
+
+impl CanonicalBits for f64 {
+    type Bits = u64;
+
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+
+    fn canonical_bits(self) -> u64 {
+        F64(self).canonical_bits()
+    }
+}
+
+/// A wrapper around any float, with the same semantics as `F32` and `F64`. This is useful for
+/// code that is generic over the width of floats.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct EqFloat<T>(pub T);
+
+/// This works like `PartialEq` on `T`, except that `NAN == NAN` is true.
+impl<T: CanonicalBits> PartialEq for EqFloat<T> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.0.is_nan() && other.0.is_nan() {
+            true
+        } else {
+            self.0 == other.0
+        }
+    }
+}
+
+impl<T: CanonicalBits> Eq for EqFloat<T> {}
+
+/// This works like `PartialOrd` on `T`, except that `NAN` sorts below all other floats
+/// (and is equal to another NAN). This always returns a `Some`.
+impl<T: CanonicalBits> PartialOrd for EqFloat<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// This works like `PartialOrd` on `T`, except that `NAN` sorts below all other floats
+/// (and is equal to another NAN).
+impl<T: CanonicalBits> Ord for EqFloat<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or_else(|| {
+            if self.0.is_nan() && !other.0.is_nan() {
+                Ordering::Less
+            } else if !self.0.is_nan() && other.0.is_nan() {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+    }
+}
+
+impl<T: CanonicalBits> Hash for EqFloat<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.canonical_bits().hash(state);
+    }
+}
+
+impl<T> From<T> for EqFloat<T> {
+    fn from(f: T) -> Self {
+        EqFloat(f)
+    }
+}
+
+impl From<EqFloat<f32>> for f32 {
+    fn from(f: EqFloat<f32>) -> Self {
+        f.0
+    }
+}
+
+impl From<EqFloat<f64>> for f64 {
+    fn from(f: EqFloat<f64>) -> Self {
+        f.0
+    }
+}
+
+impl From<F32> for EqFloat<f32> {
+    fn from(f: F32) -> Self {
+        EqFloat(f.0)
+    }
+}
+
+impl From<EqFloat<f32>> for F32 {
+    fn from(f: EqFloat<f32>) -> Self {
+        F32(f.0)
+    }
+}
+
+impl From<F64> for EqFloat<f64> {
+    fn from(f: F64) -> Self {
+        EqFloat(f.0)
+    }
+}
+
+impl From<EqFloat<f64>> for F64 {
+    fn from(f: EqFloat<f64>) -> Self {
+        F64(f.0)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for EqFloat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::EqFloat;
+    use crate::{F32, F64};
+
+    fn calculate_hash<T: Hash>(t: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+
+    #[test]
+    fn generic_matches_f32() {
+        let values = [f32::NAN, -f32::NAN, f32::NEG_INFINITY, -1.5, -0.0, 0.0, 2.0, f32::INFINITY];
+        for &a in &values {
+            for &b in &values {
+                assert_eq!(EqFloat(a) == EqFloat(b), F32(a) == F32(b));
+                assert_eq!(EqFloat(a).cmp(&EqFloat(b)), F32(a).cmp(&F32(b)));
+            }
+            assert_eq!(calculate_hash(&EqFloat(a)), calculate_hash(&F32(a)));
+            assert_eq!(F32::from(EqFloat::from(F32(a))).0.to_bits(), a.to_bits());
+        }
+    }
+
+    #[test]
+    fn generic_matches_f64() {
+        let values = [f64::NAN, -f64::NAN, f64::NEG_INFINITY, -1.5, -0.0, 0.0, 2.0, f64::INFINITY];
+        for &a in &values {
+            for &b in &values {
+                assert_eq!(EqFloat(a) == EqFloat(b), F64(a) == F64(b));
+                assert_eq!(EqFloat(a).cmp(&EqFloat(b)), F64(a).cmp(&F64(b)));
+            }
+            assert_eq!(calculate_hash(&EqFloat(a)), calculate_hash(&F64(a)));
+            assert_eq!(F64::from(EqFloat::from(F64(a))).0.to_bits(), a.to_bits());
+        }
+    }
+}
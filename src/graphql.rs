@@ -0,0 +1,178 @@
+//! `async-graphql` support: `F32` and `F64` are exposed as the built-in GraphQL `Float` scalar.
+//!
+//! GraphQL (and its JSON transport) can not represent `NAN` or the infinities. Rather than
+//! silently emitting `null` (or panicking), resolving a non-finite value yields a field error.
+
+use std::borrow::Cow;
+
+use async_graphql::parser::types::Field;
+use async_graphql::registry::Registry;
+use async_graphql::{
+    ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType, Positioned,
+    ServerError, ServerResult, Value,
+};
+
+use crate::{F32, F64};
+
+impl InputType for F32 {
+    type RawValueType = f32;
+
+    fn type_name() -> Cow<'static, str> {
+        <f32 as InputType>::type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        <f32 as InputType>::create_type_info(registry)
+    }
+
+    /// Accepts GraphQL `Float` and `Int` values.
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        <f32 as InputType>::parse(value)
+            .map(F32)
+            .map_err(InputValueError::propagate)
+    }
+
+    fn to_value(&self) -> Value {
+        <f32 as InputType>::to_value(&self.0)
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(&self.0)
+    }
+}
+
+impl OutputType for F32 {
+    fn type_name() -> Cow<'static, str> {
+        <f32 as OutputType>::type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        <f32 as OutputType>::create_type_info(registry)
+    }
+
+    /// Resolves to a GraphQL `Float`, or to a field error if the value is not finite.
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        if self.0.is_finite() {
+            <f32 as OutputType>::resolve(&self.0, ctx, field).await
+        } else {
+            Err(non_finite_error(self.0.into(), field))
+        }
+    }
+}
+
+impl InputType for F64 {
+    type RawValueType = f64;
+
+    fn type_name() -> Cow<'static, str> {
+        <f64 as InputType>::type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        <f64 as InputType>::create_type_info(registry)
+    }
+
+    /// Accepts GraphQL `Float` and `Int` values.
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        <f64 as InputType>::parse(value)
+            .map(F64)
+            .map_err(InputValueError::propagate)
+    }
+
+    fn to_value(&self) -> Value {
+        <f64 as InputType>::to_value(&self.0)
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(&self.0)
+    }
+}
+
+impl OutputType for F64 {
+    fn type_name() -> Cow<'static, str> {
+        <f64 as OutputType>::type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        <f64 as OutputType>::create_type_info(registry)
+    }
+
+    /// Resolves to a GraphQL `Float`, or to a field error if the value is not finite.
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        if self.0.is_finite() {
+            <f64 as OutputType>::resolve(&self.0, ctx, field).await
+        } else {
+            Err(non_finite_error(self.0, field))
+        }
+    }
+}
+
+fn non_finite_error(value: f64, field: &Positioned<Field>) -> ServerError {
+    ServerError::new(
+        format!("{} can not be represented as a GraphQL Float", value),
+        Some(field.pos),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{value, EmptyMutation, EmptySubscription, Object, Schema};
+    use futures::executor::block_on;
+
+    use crate::{F32, F64};
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn single(&self) -> F32 {
+            F32(0.25)
+        }
+
+        async fn double(&self) -> F64 {
+            F64(1.5)
+        }
+
+        async fn echo(&self, value: F64) -> F64 {
+            value
+        }
+
+        async fn nan(&self) -> F64 {
+            F64(f64::NAN)
+        }
+    }
+
+    fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+        Schema::new(Query, EmptyMutation, EmptySubscription)
+    }
+
+    #[test]
+    fn graphql_finite() {
+        let res = block_on(schema().execute("{ single double echo(value: 3) }"));
+        assert!(res.errors.is_empty());
+        assert_eq!(
+            res.data,
+            value!({ "single": 0.25, "double": 1.5, "echo": 3.0 })
+        );
+
+        let res = block_on(schema().execute("{ echo(value: 2.5) }"));
+        assert_eq!(res.data, value!({ "echo": 2.5 }));
+    }
+
+    #[test]
+    fn graphql_nan_is_field_error() {
+        let res = block_on(schema().execute("{ nan }"));
+        assert_eq!(res.errors.len(), 1);
+        assert_eq!(
+            res.errors[0].message,
+            "NaN can not be represented as a GraphQL Float"
+        );
+    }
+}
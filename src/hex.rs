@@ -0,0 +1,402 @@
+//! Hexadecimal float literals in the style of C99 (`0x1.8p3`, `-0x1p-126`), for bit-exact
+//! round-tripping of floats through text.
+//!
+//! `to_hex_string` writes normal values as `0x1.<hex digits>p<exponent>` and subnormal values as
+//! `0x0.<hex digits>p<minimum exponent>`, omitting trailing zero digits (and the point if there
+//! are none), and writes `inf`, `-inf` and `nan` for the special values. `from_hex_str` accepts
+//! these, and more generally an optional sign followed by `0x` (or `0X`), hex digits with an
+//! optional point, and an optional binary exponent (`p` or `P`, an optional sign, and decimal
+//! digits). It also accepts `inf`, `infinity` and `nan` in any case. Values are rounded to the
+//! nearest float (ties to even), so literals with more digits than fit are fine as well.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+
+use crate::{F32, F64};
+
+/// The error returned by `F32::from_hex_str` and `F64::from_hex_str`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseHexFloatError(());
+
+impl fmt::Display for ParseHexFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid hexadecimal float literal")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseHexFloatError {}
+
+/// A parsed literal: `(-1)^negative * mantissa * 2^exponent`, plus whether any nonzero digits
+/// did not fit into the mantissa.
+struct Literal {
+    negative: bool,
+    mantissa: u64,
+    exponent: i64,
+    sticky: bool,
+}
+
+enum Parsed {
+    Infinity(bool),
+    Nan,
+    Finite(Literal),
+}
+
+fn parse(s: &str) -> Result<Parsed, ParseHexFloatError> {
+    let err = ParseHexFloatError(());
+    let (negative, rest) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    if rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity") {
+        return Ok(Parsed::Infinity(negative));
+    } else if rest.eq_ignore_ascii_case("nan") {
+        return Ok(Parsed::Nan);
+    }
+
+    let bytes = rest.as_bytes();
+    if bytes.len() < 2 || bytes[0] != b'0' || (bytes[1] != b'x' && bytes[1] != b'X') {
+        return Err(err);
+    }
+
+    let mut literal = Literal {
+        negative,
+        mantissa: 0,
+        exponent: 0,
+        sticky: false,
+    };
+    let mut digits = 0;
+    let mut seen_point = false;
+    let mut i = 2;
+    while i < bytes.len() {
+        let digit = match bytes[i] {
+            b'.' if !seen_point => {
+                seen_point = true;
+                i += 1;
+                continue;
+            }
+            b @ b'0'..=b'9' => b - b'0',
+            b @ b'a'..=b'f' => b - b'a' + 10,
+            b @ b'A'..=b'F' => b - b'A' + 10,
+            _ => break,
+        };
+        digits += 1;
+        if literal.mantissa >> 60 == 0 {
+            literal.mantissa = (literal.mantissa << 4) | u64::from(digit);
+            if seen_point {
+                literal.exponent -= 4;
+            }
+        } else {
+            literal.sticky |= digit != 0;
+            if !seen_point {
+                literal.exponent += 4;
+            }
+        }
+        i += 1;
+    }
+    if digits == 0 {
+        return Err(err);
+    }
+
+    if i < bytes.len() {
+        if bytes[i] != b'p' && bytes[i] != b'P' {
+            return Err(err);
+        }
+        i += 1;
+        let exponent_negative = match bytes.get(i) {
+            Some(b'-') => {
+                i += 1;
+                true
+            }
+            Some(b'+') => {
+                i += 1;
+                false
+            }
+            _ => false,
+        };
+        if i == bytes.len() {
+            return Err(err);
+        }
+        let mut exponent: i64 = 0;
+        for &b in &bytes[i..] {
+            if !b.is_ascii_digit() {
+                return Err(err);
+            }
+            // Saturate, anything this large over- or underflows anyway.
+            exponent = (exponent * 10 + i64::from(b - b'0')).min(1 << 20);
+        }
+        literal.exponent += if exponent_negative {
+            -exponent
+        } else {
+            exponent
+        };
+    }
+
+    Ok(Parsed::Finite(literal))
+}
+
+/// Rounds the literal to the nearest float with `mantissa_bits` explicit mantissa bits and the
+/// given maximum exponent, and returns the bits of that float.
+fn compose(literal: Literal, mantissa_bits: u32, max_exponent: i64) -> u64 {
+    let sign = u64::from(literal.negative) << (mantissa_bits + exponent_bits(max_exponent));
+    if literal.mantissa == 0 {
+        return sign;
+    }
+    let infinity = sign | (((max_exponent as u64) * 2 + 1) << mantissa_bits);
+    let min_exponent = 1 - max_exponent;
+    let precision = i64::from(mantissa_bits) + 1;
+
+    // Normalize so that the mantissa has its most significant bit set.
+    let zeros = literal.mantissa.leading_zeros();
+    let mantissa = u128::from(literal.mantissa << zeros);
+    // The exponent of the most significant bit.
+    let exponent = literal.exponent - i64::from(zeros) + 63;
+    if exponent > max_exponent {
+        return infinity;
+    }
+
+    let shift = (64 - precision + (min_exponent - exponent).max(0)).min(66) as u32;
+    let mut rounded = mantissa >> shift;
+    let remainder = mantissa & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if remainder > half || (remainder == half && (literal.sticky || rounded & 1 == 1)) {
+        rounded += 1;
+    }
+
+    let mut rounded = rounded as u64;
+    if exponent < min_exponent {
+        // Subnormal (or zero). If rounding carried into the implicit bit, the resulting bits
+        // are exactly those of the least normal float.
+        sign | rounded
+    } else {
+        let mut exponent = exponent;
+        if rounded >> (mantissa_bits + 1) != 0 {
+            rounded >>= 1;
+            exponent += 1;
+            if exponent > max_exponent {
+                return infinity;
+            }
+        }
+        let biased = (exponent + max_exponent) as u64;
+        sign | (biased << mantissa_bits) | (rounded & ((1 << mantissa_bits) - 1))
+    }
+}
+
+fn exponent_bits(max_exponent: i64) -> u32 {
+    64 - (max_exponent as u64).leading_zeros() + 1
+}
+
+/// Writes the sign and the digits of a finite value, given its explicit mantissa bits (padded
+/// to a multiple of four bits), its biased exponent and its exponent bias.
+#[cfg(feature = "alloc")]
+fn write_finite(negative: bool, mantissa: u64, digits: usize, biased: i64, bias: i64) -> String {
+    let mut s = String::new();
+    if negative {
+        s.push('-');
+    }
+    if mantissa == 0 && biased == 0 {
+        s.push_str("0x0p0");
+        return s;
+    }
+
+    let (lead, exponent) = if biased == 0 {
+        (0, 1 - bias)
+    } else {
+        (1, biased - bias)
+    };
+    write!(s, "0x{}", lead).unwrap();
+    if mantissa != 0 {
+        let mut hex = String::new();
+        write!(hex, "{:01$x}", mantissa, digits).unwrap();
+        s.push('.');
+        s.push_str(hex.trim_end_matches('0'));
+    }
+    write!(s, "p{}", exponent).unwrap();
+    s
+}
+
+impl F32 {
+    /// Parses a hexadecimal float literal such as `0x1.8p3` or `-0x1p-126`, as well as `inf`
+    /// and `nan` (see the module documentation for the full grammar), rounding to the nearest
+    /// `f32`.
+    pub fn from_hex_str(s: &str) -> Result<F32, ParseHexFloatError> {
+        Ok(F32(match parse(s)? {
+            Parsed::Infinity(false) => f32::INFINITY,
+            Parsed::Infinity(true) => f32::NEG_INFINITY,
+            Parsed::Nan => f32::NAN,
+            Parsed::Finite(literal) => f32::from_bits(compose(literal, 23, 127) as u32),
+        }))
+    }
+
+    /// Formats the value as a hexadecimal float literal, which `from_hex_str` parses back to
+    /// exactly the same bits (all `NAN` are written as `nan` though).
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string(self) -> String {
+        if self.0.is_nan() {
+            String::from("nan")
+        } else if self.0.is_infinite() {
+            String::from(if self.0 > 0.0 { "inf" } else { "-inf" })
+        } else {
+            let bits = self.0.to_bits();
+            let mantissa = u64::from(bits & 0x7f_ffff) << 1;
+            let biased = i64::from((bits >> 23) & 0xff);
+            write_finite(self.0.is_sign_negative(), mantissa, 6, biased, 127)
+        }
+    }
+}
+
+impl F64 {
+    /// Parses a hexadecimal float literal such as `0x1.8p3` or `-0x1p-1022`, as well as `inf`
+    /// and `nan` (see the module documentation for the full grammar), rounding to the nearest
+    /// `f64`.
+    pub fn from_hex_str(s: &str) -> Result<F64, ParseHexFloatError> {
+        Ok(F64(match parse(s)? {
+            Parsed::Infinity(false) => f64::INFINITY,
+            Parsed::Infinity(true) => f64::NEG_INFINITY,
+            Parsed::Nan => f64::NAN,
+            Parsed::Finite(literal) => f64::from_bits(compose(literal, 52, 1023)),
+        }))
+    }
+
+    /// Formats the value as a hexadecimal float literal, which `from_hex_str` parses back to
+    /// exactly the same bits (all `NAN` are written as `nan` though).
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string(self) -> String {
+        if self.0.is_nan() {
+            String::from("nan")
+        } else if self.0.is_infinite() {
+            String::from(if self.0 > 0.0 { "inf" } else { "-inf" })
+        } else {
+            let bits = self.0.to_bits();
+            let mantissa = bits & 0xf_ffff_ffff_ffff;
+            let biased = ((bits >> 52) & 0x7ff) as i64;
+            write_finite(self.0.is_sign_negative(), mantissa, 13, biased, 1023)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f32_hex(s: &str) -> f32 {
+        F32::from_hex_str(s).unwrap().0
+    }
+
+    fn f64_hex(s: &str) -> f64 {
+        F64::from_hex_str(s).unwrap().0
+    }
+
+    #[test]
+    fn parse_fixtures() {
+        assert_eq!(f64_hex("0x1.8p3"), 12.0);
+        assert_eq!(f64_hex("0X1P+2"), 4.0);
+        assert_eq!(f64_hex("0x.8p1"), 1.0);
+        assert_eq!(f64_hex("0x10"), 16.0);
+        assert_eq!(f64_hex("+0xa.8"), 10.5);
+        assert_eq!(f64_hex("-0x0p0").to_bits(), (-0.0f64).to_bits());
+        assert_eq!(f64_hex("0x1p-1022"), f64::MIN_POSITIVE);
+        assert_eq!(f64_hex("0x1p-1074"), f64::from_bits(1));
+        assert_eq!(f64_hex("0x0.0000000000001p-1022"), f64::from_bits(1));
+        assert_eq!(f64_hex("0x1.fffffffffffffp1023"), f64::MAX);
+        assert_eq!(f64_hex("0x1p1024"), f64::INFINITY);
+        assert_eq!(f64_hex("-0x1p99999999999"), f64::NEG_INFINITY);
+        assert_eq!(f64_hex("0x1p-99999999999").to_bits(), 0);
+
+        // Rounding, ties to even.
+        assert_eq!(f64_hex("0x1p-1075").to_bits(), 0);
+        assert_eq!(f64_hex("0x1.8p-1075"), f64::from_bits(1));
+        assert_eq!(f64_hex("0x1.8p-1074"), f64::from_bits(2));
+        assert_eq!(f64_hex("0x1.00000000000008p0"), 1.0);
+        assert_eq!(
+            f64_hex("0x1.000000000000080000000001p0"),
+            1.0 + f64::EPSILON
+        );
+        assert_eq!(f64_hex("0x1.00000000000018p0"), 1.0 + 2.0 * f64::EPSILON);
+        assert_eq!(f64_hex("0x1.fffffffffffff7p1023"), f64::MAX);
+        assert_eq!(f64_hex("0x1.fffffffffffff8p1023"), f64::INFINITY);
+        assert_eq!(f64_hex("0x0.fffffffffffff8p-1022"), f64::MIN_POSITIVE);
+        assert_eq!(
+            f64_hex("0x123456789abcdef0123p-72"),
+            f64_hex("0x1.23456789abcdfp0")
+        );
+
+        assert_eq!(f32_hex("-0x1p-126"), -f32::MIN_POSITIVE);
+        assert_eq!(f32_hex("0x1p-149"), f32::from_bits(1));
+        assert_eq!(f32_hex("0x1.000001p0"), 1.0);
+        assert_eq!(f32_hex("0x1.000003p0"), 1.0 + 2.0 * f32::EPSILON);
+        assert_eq!(f32_hex("0x1.fffffep127"), f32::MAX);
+        assert_eq!(f32_hex("0x1p128"), f32::INFINITY);
+
+        assert_eq!(f64_hex("inf"), f64::INFINITY);
+        assert_eq!(f64_hex("-Infinity"), f64::NEG_INFINITY);
+        assert!(f64_hex("NaN").is_nan());
+        assert!(f32_hex("-nan").is_nan());
+    }
+
+    #[test]
+    fn parse_errors() {
+        for s in [
+            "", "-", "0x", "0x.", "1.5", "0x1p", "0x1p-", "0xg", "0x1.2.3", "0x1p1.5", "--0x1",
+            "0x1 ", " 0x1", "0x1q2", "infinit", "0x-1",
+        ] {
+            assert_eq!(F64::from_hex_str(s), Err(ParseHexFloatError(())));
+            assert_eq!(F32::from_hex_str(s), Err(ParseHexFloatError(())));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn format() {
+        assert_eq!(F64(12.0).to_hex_string(), "0x1.8p3");
+        assert_eq!(F64(1.0).to_hex_string(), "0x1p0");
+        assert_eq!(F64(-0.0).to_hex_string(), "-0x0p0");
+        assert_eq!(
+            F64(f64::from_bits(1)).to_hex_string(),
+            "0x0.0000000000001p-1022"
+        );
+        assert_eq!(F64(f64::MAX).to_hex_string(), "0x1.fffffffffffffp1023");
+        assert_eq!(F64(f64::NEG_INFINITY).to_hex_string(), "-inf");
+        assert_eq!(F64(-f64::NAN).to_hex_string(), "nan");
+
+        assert_eq!(F32(-f32::MIN_POSITIVE).to_hex_string(), "-0x1p-126");
+        assert_eq!(F32(0.1).to_hex_string(), "0x1.99999ap-4");
+        assert_eq!(F32(f32::from_bits(1)).to_hex_string(), "0x0.000002p-126");
+        assert_eq!(F32(f32::INFINITY).to_hex_string(), "inf");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        for state in crate::tests::random_bits_u64(0x2545_f491_4f6c_dd1d).take(100_000) {
+            let f = F64(f64::from_bits(state));
+            let parsed = F64::from_hex_str(&f.to_hex_string()).unwrap();
+            if f.0.is_nan() {
+                assert_eq!(parsed, f);
+            } else {
+                assert_eq!(parsed.0.to_bits(), f.0.to_bits());
+            }
+
+            let f = F32(f32::from_bits(state as u32));
+            let parsed = F32::from_hex_str(&f.to_hex_string()).unwrap();
+            if f.0.is_nan() {
+                assert_eq!(parsed, f);
+            } else {
+                assert_eq!(parsed.0.to_bits(), f.0.to_bits());
+            }
+        }
+
+        for bits in [0, 1, 0x7f_ffff, 0x80_0000, 0x7f7f_ffff, 0x8000_0001] {
+            let f = F32(f32::from_bits(bits));
+            assert_eq!(
+                F32::from_hex_str(&f.to_hex_string()).unwrap().0.to_bits(),
+                bits
+            );
+        }
+    }
+}
@@ -0,0 +1,460 @@
+//! Conversions between the wrappers and the integer types: lossless `From` impls for the integers
+//! that always fit, `TryFrom` impls (failing with an `InexactIntError`) and nearest or exact
+//! constructors for the others, and checked, truncating and saturating conversions back to
+//! integers. Also holds the decimal fixed-point helpers `F64::to_scaled_i64` and
+//! `F64::from_scaled_i64`.
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::{F32, F64};
+
+/// The error returned when converting an integer to an `F64` would round it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InexactIntError<T> {
+    /// The integer that has no exact `f64` representation.
+    pub value: T,
+}
+
+impl<T: fmt::Display> fmt::Display for InexactIntError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} can not be represented exactly as an f64", self.value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + fmt::Display> std::error::Error for InexactIntError<T> {}
+
+// Lossless conversions, exactly those that std provides for the primitives.
+macro_rules! impl_from_int {
+    ($t:ident, $f:ident, $($int:ty),*) => {
+        $(
+            impl From<$int> for $t {
+                fn from(i: $int) -> Self {
+                    $t($f::from(i))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(F32, f32, i8, i16, u8, u16);
+impl_from_int!(F64, f64, i8, i16, i32, u8, u16, u32);
+
+// Conversions that succeed if the integer is exactly representable. `$limit` is the least power
+// of two that is out of range for `$int`, which is the only out-of-range value the cast to
+// `f64` can produce (and which would saturate when cast back).
+macro_rules! impl_try_from_int {
+    ($($int:ty, $limit:expr;)*) => {
+        $(
+            /// Succeeds if the integer can be represented exactly, which is the case for all
+            /// integers of magnitude at most `2^53`, and for some larger ones.
+            impl TryFrom<$int> for F64 {
+                type Error = InexactIntError<$int>;
+
+                fn try_from(i: $int) -> Result<Self, Self::Error> {
+                    let f = i as f64;
+                    if f < $limit && f as $int == i {
+                        Ok(F64(f))
+                    } else {
+                        Err(InexactIntError { value: i })
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_int!(
+    i64, 9_223_372_036_854_775_808.0;
+    u64, 18_446_744_073_709_551_616.0;
+    i128, 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+    u128, 340_282_366_920_938_463_463_374_607_431_768_211_456.0;
+);
+
+impl F32 {
+    /// Converts to the nearest `f32`, rounding to an even mantissa on ties, like `as f32` does.
+    /// Integers with a magnitude above `2^24` are not all representable, so they may be
+    /// rounded.
+    pub fn from_i64_nearest(i: i64) -> Self {
+        F32(i as f32)
+    }
+
+    /// Converts to the nearest `f32`, rounding to an even mantissa on ties, like `as f32` does.
+    /// Integers above `2^24` are not all representable, so they may be rounded.
+    pub fn from_u64_nearest(i: u64) -> Self {
+        F32(i as f32)
+    }
+
+    /// Converts to an `f32`, or returns `None` if the integer can not be represented exactly.
+    pub fn from_i64_exact(i: i64) -> Option<Self> {
+        let f = i as f32;
+        // `2^63` is the only value `f` can take that is not in range, and it would saturate to
+        // `i64::MAX` when cast back.
+        if f < 9_223_372_036_854_775_808.0 && f as i64 == i {
+            Some(F32(f))
+        } else {
+            None
+        }
+    }
+
+    /// Converts to an `f32`, or returns `None` if the integer can not be represented exactly.
+    pub fn from_u64_exact(i: u64) -> Option<Self> {
+        let f = i as f32;
+        // `2^64` is the only value `f` can take that is not in range, and it would saturate to
+        // `u64::MAX` when cast back.
+        if f < 18_446_744_073_709_551_616.0 && f as u64 == i {
+            Some(F32(f))
+        } else {
+            None
+        }
+    }
+}
+
+impl F64 {
+    /// Converts to the nearest `f64`, rounding to an even mantissa on ties, like `as f64` does.
+    /// Integers with a magnitude above `2^53` are not all representable, so they may be
+    /// rounded.
+    pub fn from_i64_nearest(i: i64) -> Self {
+        F64(i as f64)
+    }
+
+    /// Converts to the nearest `f64`, rounding to an even mantissa on ties, like `as f64` does.
+    /// Integers above `2^53` are not all representable, so they may be rounded.
+    pub fn from_u64_nearest(i: u64) -> Self {
+        F64(i as f64)
+    }
+
+    /// Converts to an `f64`, or returns `None` if the integer can not be represented exactly.
+    pub fn from_i64_exact(i: i64) -> Option<Self> {
+        let f = i as f64;
+        // `2^63` is the only value `f` can take that is not in range, and it would saturate to
+        // `i64::MAX` when cast back.
+        if f < 9_223_372_036_854_775_808.0 && f as i64 == i {
+            Some(F64(f))
+        } else {
+            None
+        }
+    }
+
+    /// Converts to an `f64`, or returns `None` if the integer can not be represented exactly.
+    pub fn from_u64_exact(i: u64) -> Option<Self> {
+        let f = i as f64;
+        // `2^64` is the only value `f` can take that is not in range, and it would saturate to
+        // `u64::MAX` when cast back.
+        if f < 18_446_744_073_709_551_616.0 && f as u64 == i {
+            Some(F64(f))
+        } else {
+            None
+        }
+    }
+}
+
+impl F32 {
+    /// Converts to an `i64` if the value is an integer in the range of `i64`, and returns `None`
+    /// otherwise (including for `NAN` and the infinities).
+    pub fn to_i64_checked(self) -> Option<i64> {
+        self.to_i64_truncated().filter(|&i| i as f32 == self.0)
+    }
+
+    /// Converts to an `i64`, discarding the fractional part, if the result is in the range of
+    /// `i64`, and returns `None` otherwise (including for `NAN` and the infinities).
+    pub fn to_i64_truncated(self) -> Option<i64> {
+        if self.0 >= -9_223_372_036_854_775_808.0 && self.0 < 9_223_372_036_854_775_808.0 {
+            Some(self.0 as i64)
+        } else {
+            None
+        }
+    }
+
+    /// Converts to an `i64` like `as i64` does: the fractional part is discarded, values out of
+    /// range saturate to `i64::MIN` or `i64::MAX`, and `NAN` becomes `0`.
+    pub fn to_i64_saturating(self) -> i64 {
+        self.0 as i64
+    }
+
+    /// Converts to a `u64` if the value is an integer in the range of `u64`, and returns `None`
+    /// otherwise (including for `NAN` and the infinities).
+    pub fn to_u64_checked(self) -> Option<u64> {
+        self.to_u64_truncated().filter(|&i| i as f32 == self.0)
+    }
+
+    /// Converts to a `u64`, discarding the fractional part, if the result is in the range of
+    /// `u64`, and returns `None` otherwise (including for `NAN` and the infinities).
+    pub fn to_u64_truncated(self) -> Option<u64> {
+        if self.0 > -1.0 && self.0 < 18_446_744_073_709_551_616.0 {
+            Some(self.0 as u64)
+        } else {
+            None
+        }
+    }
+
+    /// Converts to a `u64` like `as u64` does: the fractional part is discarded, values out of
+    /// range saturate to `0` or `u64::MAX`, and `NAN` becomes `0`.
+    pub fn to_u64_saturating(self) -> u64 {
+        self.0 as u64
+    }
+
+    /// Converts to a `usize` like `as usize` does: the fractional part is discarded, values out
+    /// of range saturate to `0` or `usize::MAX`, and `NAN` becomes `0`.
+    pub fn to_usize_saturating(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl F64 {
+    /// Converts to an `i64` if the value is an integer in the range of `i64`, and returns `None`
+    /// otherwise (including for `NAN` and the infinities).
+    pub fn to_i64_checked(self) -> Option<i64> {
+        self.to_i64_truncated().filter(|&i| i as f64 == self.0)
+    }
+
+    /// Converts to an `i64`, discarding the fractional part, if the result is in the range of
+    /// `i64`, and returns `None` otherwise (including for `NAN` and the infinities).
+    pub fn to_i64_truncated(self) -> Option<i64> {
+        if self.0 >= -9_223_372_036_854_775_808.0 && self.0 < 9_223_372_036_854_775_808.0 {
+            Some(self.0 as i64)
+        } else {
+            None
+        }
+    }
+
+    /// Converts to an `i64` like `as i64` does: the fractional part is discarded, values out of
+    /// range saturate to `i64::MIN` or `i64::MAX`, and `NAN` becomes `0`.
+    pub fn to_i64_saturating(self) -> i64 {
+        self.0 as i64
+    }
+
+    /// Converts to a `u64` if the value is an integer in the range of `u64`, and returns `None`
+    /// otherwise (including for `NAN` and the infinities).
+    pub fn to_u64_checked(self) -> Option<u64> {
+        self.to_u64_truncated().filter(|&i| i as f64 == self.0)
+    }
+
+    /// Converts to a `u64`, discarding the fractional part, if the result is in the range of
+    /// `u64`, and returns `None` otherwise (including for `NAN` and the infinities).
+    pub fn to_u64_truncated(self) -> Option<u64> {
+        if self.0 > -1.0 && self.0 < 18_446_744_073_709_551_616.0 {
+            Some(self.0 as u64)
+        } else {
+            None
+        }
+    }
+
+    /// Converts to a `u64` like `as u64` does: the fractional part is discarded, values out of
+    /// range saturate to `0` or `u64::MAX`, and `NAN` becomes `0`.
+    pub fn to_u64_saturating(self) -> u64 {
+        self.0 as u64
+    }
+
+    /// Converts to a `usize` like `as usize` does: the fractional part is discarded, values out
+    /// of range saturate to `0` or `usize::MAX`, and `NAN` becomes `0`.
+    pub fn to_usize_saturating(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl F64 {
+    /// Multiplies by `10^scale` and rounds to the nearest integer (ties away from zero), e.g.
+    /// `F64(1.2345).to_scaled_i64(2) == Some(123)`. Returns `None` for `NAN` and for results out
+    /// of the range of `i64`.
+    ///
+    /// The multiplication happens in `f64`, so the result can differ from rounding the decimal
+    /// value the float was written as when that value is very close to a tie.
+    pub fn to_scaled_i64(self, scale: u32) -> Option<i64> {
+        let scaled = self.0 * power_of_ten(scale);
+        let truncated = F64(scaled).to_i64_truncated()?;
+        // Out-of-range values were rejected, and floats of magnitude at least `2^53` have no
+        // fractional part, so neither the subtraction nor the adjustment can overflow.
+        let fraction = scaled - truncated as f64;
+        if fraction >= 0.5 {
+            Some(truncated + 1)
+        } else if fraction <= -0.5 {
+            Some(truncated - 1)
+        } else {
+            Some(truncated)
+        }
+    }
+
+    /// Divides `value` by `10^scale`, the inverse of `to_scaled_i64` up to rounding.
+    pub fn from_scaled_i64(value: i64, scale: u32) -> Self {
+        F64(value as f64 / power_of_ten(scale))
+    }
+}
+
+/// The powers of ten that are exactly representable as `f64`.
+const EXACT_POWERS_OF_TEN: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+// `10^exponent`, exact up to `10^22`, and infinite from `10^309` on.
+fn power_of_ten(exponent: u32) -> f64 {
+    match exponent {
+        0..=22 => EXACT_POWERS_OF_TEN[exponent as usize],
+        23..=308 => (22..exponent).fold(1e22, |power, _| power * 10.0),
+        _ => f64::INFINITY,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_int() {
+        assert_eq!(F64::from(3u32), F64(3.0));
+        assert_eq!(F64::from(i32::MIN), F64(-2147483648.0));
+        assert_eq!(F64::from(0u8), F64(0.0));
+        assert_eq!(F32::from(-5i8), F32(-5.0));
+        assert_eq!(F32::from(u16::MAX), F32(65535.0));
+    }
+
+    #[test]
+    fn try_from_int() {
+        assert_eq!(F64::try_from(1i64 << 53), Ok(F64(9007199254740992.0)));
+        assert_eq!(
+            F64::try_from((1i64 << 53) + 1),
+            Err(InexactIntError {
+                value: (1 << 53) + 1
+            })
+        );
+        assert_eq!(F64::try_from(-(1i64 << 53)), Ok(F64(-9007199254740992.0)));
+        assert_eq!(F64::try_from(0u64), Ok(F64(0.0)));
+        assert_eq!(F64::try_from(-7i128), Ok(F64(-7.0)));
+        assert_eq!(F64::try_from(i128::MIN), Ok(F64(-(2.0f64.powi(127)))));
+        assert_eq!(
+            F64::try_from(i128::MAX),
+            Err(InexactIntError { value: i128::MAX })
+        );
+        assert_eq!(
+            F64::try_from(u128::MAX),
+            Err(InexactIntError { value: u128::MAX })
+        );
+        assert_eq!(
+            F64::try_from((1u128 << 53) + 1),
+            Err(InexactIntError {
+                value: (1 << 53) + 1
+            })
+        );
+        assert_eq!(F64::try_from(1u128 << 100), Ok(F64(2.0f64.powi(100))));
+    }
+
+    #[test]
+    fn f32_from_i64() {
+        assert_eq!(F32::from_i64_exact(1 << 30), Some(F32(1073741824.0)));
+        assert_eq!(F32::from_i64_exact((1 << 25) + 1), None);
+        assert_eq!(F32::from_i64_exact(-(1 << 24)), Some(F32(-16777216.0)));
+        assert_eq!(
+            F32::from_i64_exact(i64::MIN),
+            Some(F32(-9223372036854775808.0))
+        );
+        assert_eq!(F32::from_i64_exact(i64::MAX), None);
+        assert_eq!(F32::from_u64_exact(u64::MAX), None);
+        assert_eq!(
+            F32::from_u64_exact(1 << 63),
+            Some(F32(9223372036854775808.0))
+        );
+        assert_eq!(F32::from_i64_nearest((1 << 25) + 1), F32(33554432.0));
+        assert_eq!(F32::from_i64_nearest((1 << 25) + 3), F32(33554436.0));
+        assert_eq!(F32::from_u64_nearest(u64::MAX), F32(18446744073709551616.0));
+    }
+
+    #[test]
+    fn f64_from_i64() {
+        assert_eq!(F64::from_i64_exact(1 << 53), Some(F64(9007199254740992.0)));
+        assert_eq!(F64::from_i64_exact((1 << 53) + 1), None);
+        assert_eq!(
+            F64::from_i64_exact(-(1 << 53)),
+            Some(F64(-9007199254740992.0))
+        );
+        assert_eq!(
+            F64::from_i64_exact(i64::MIN),
+            Some(F64(-9223372036854775808.0))
+        );
+        assert_eq!(F64::from_i64_exact(i64::MAX), None);
+        assert_eq!(F64::from_u64_exact(u64::MAX), None);
+        assert_eq!(F64::from_u64_exact(0), Some(F64(0.0)));
+        assert_eq!(
+            F64::from_i64_nearest((1 << 53) + 1),
+            F64(9007199254740992.0)
+        );
+        assert_eq!(F64::from_u64_nearest(u64::MAX), F64(18446744073709551616.0));
+    }
+
+    #[test]
+    fn f32_to_int() {
+        assert_eq!(F32(3.0).to_i64_checked(), Some(3));
+        assert_eq!(F32(-3.5).to_i64_checked(), None);
+        assert_eq!(F32(-3.5).to_i64_truncated(), Some(-3));
+        assert_eq!(F32(-9223372036854775808.0).to_i64_checked(), Some(i64::MIN));
+        assert_eq!(F32(9223372036854775808.0).to_i64_truncated(), None);
+        assert_eq!(F32(f32::NAN).to_i64_truncated(), None);
+        assert_eq!(F32(f32::INFINITY).to_u64_truncated(), None);
+        assert_eq!(F32(-0.5).to_u64_truncated(), Some(0));
+        assert_eq!(F32(-0.5).to_u64_checked(), None);
+        assert_eq!(F32(-1.0).to_u64_truncated(), None);
+        assert_eq!(F32(f32::NAN).to_i64_saturating(), 0);
+        assert_eq!(F32(1e30).to_i64_saturating(), i64::MAX);
+        assert_eq!(F32(-1.0).to_u64_saturating(), 0);
+        assert_eq!(F32(f32::NAN).to_usize_saturating(), 0);
+        assert_eq!(F32(7.9).to_usize_saturating(), 7);
+    }
+
+    #[test]
+    fn f64_to_int() {
+        let max = 9223372036854775808.0;
+        let below_max = f64::from_bits(f64::to_bits(max) - 1);
+        assert_eq!(F64(below_max).to_i64_checked(), Some(i64::MAX - 1023));
+        assert_eq!(F64(max).to_i64_checked(), None);
+        assert_eq!(F64(max).to_i64_truncated(), None);
+        assert_eq!(F64(max).to_i64_saturating(), i64::MAX);
+        assert_eq!(F64(-max).to_i64_checked(), Some(i64::MIN));
+        assert_eq!(F64(max).to_u64_checked(), Some(1 << 63));
+        assert_eq!(F64(2.5).to_i64_checked(), None);
+        assert_eq!(F64(2.5).to_i64_truncated(), Some(2));
+        assert_eq!(F64(-0.0).to_i64_checked(), Some(0));
+        assert_eq!(F64(-0.0).to_u64_checked(), Some(0));
+        assert_eq!(F64(-2.0).to_u64_checked(), None);
+        assert_eq!(F64(-2.0).to_u64_saturating(), 0);
+        assert_eq!(F64(f64::NAN).to_i64_checked(), None);
+        assert_eq!(F64(f64::NAN).to_u64_saturating(), 0);
+        assert_eq!(F64(f64::NEG_INFINITY).to_i64_saturating(), i64::MIN);
+        assert_eq!(F64(f64::INFINITY).to_usize_saturating(), usize::MAX);
+    }
+
+    #[test]
+    fn powers_of_ten() {
+        for (exponent, power) in EXACT_POWERS_OF_TEN.iter().enumerate() {
+            assert_eq!(power_of_ten(exponent as u32), *power);
+        }
+        assert!((power_of_ten(308) / 1e308 - 1.0).abs() < 1e-14);
+        assert_eq!(power_of_ten(309), f64::INFINITY);
+        assert_eq!(power_of_ten(u32::MAX), f64::INFINITY);
+    }
+
+    #[test]
+    fn scaled_i64() {
+        assert_eq!(F64(1.2345).to_scaled_i64(2), Some(123));
+        assert_eq!(F64::from_scaled_i64(123, 2), F64(1.23));
+        assert_eq!(F64(1.2345).to_scaled_i64(0), Some(1));
+        assert_eq!(F64(2.5).to_scaled_i64(0), Some(3));
+        assert_eq!(F64(-2.5).to_scaled_i64(0), Some(-3));
+        assert_eq!(F64(-0.014).to_scaled_i64(2), Some(-1));
+        assert_eq!(F64(0.125).to_scaled_i64(4), Some(1250));
+        assert_eq!(F64(f64::NAN).to_scaled_i64(2), None);
+        assert_eq!(F64(f64::INFINITY).to_scaled_i64(0), None);
+        assert_eq!(F64(1e17).to_scaled_i64(2), None);
+        assert_eq!(
+            F64(-9.2e18).to_scaled_i64(0),
+            Some(-9_200_000_000_000_000_000)
+        );
+        assert_eq!(F64::from_scaled_i64(-5, 0), F64(-5.0));
+        assert_eq!(F64::from_scaled_i64(15, 1), F64(1.5));
+        assert_eq!(F64::from_scaled_i64(1, u32::MAX), F64(0.0));
+        assert_eq!(F64::from_scaled_i64(i64::MAX, 400), F64(0.0));
+        assert_eq!(F64(1.0).to_scaled_i64(u32::MAX), None);
+        assert_eq!(F64(0.0).to_scaled_i64(u32::MAX), None);
+        assert_eq!(F64(1e-300).to_scaled_i64(300), Some(1));
+    }
+}
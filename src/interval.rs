@@ -0,0 +1,170 @@
+//! `Interval64`, an interval of `F64` values whose arithmetic rounds outward, so that the result
+//! always contains the exact result.
+
+use core::fmt;
+use core::ops::{Add, Mul, Sub};
+
+use crate::F64;
+
+/// A closed interval `[lo, hi]` of `f64` values, for conservative (interval) arithmetic.
+///
+/// The arithmetic operations round outward: the bounds of a result are moved one step away from
+/// each other, so that the result contains the exact result of the operation on any values of
+/// the operands, despite rounding errors. Operations that involve a `NAN` bound, or that are
+/// undefined (such as multiplying zero by infinity), yield a `NAN` bound, which `is_valid`
+/// detects.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval64 {
+    /// The lower bound.
+    pub lo: F64,
+    /// The upper bound.
+    pub hi: F64,
+}
+
+impl Interval64 {
+    /// Creates the interval `[lo, hi]`. This does not check whether the interval is valid.
+    pub fn new(lo: F64, hi: F64) -> Self {
+        Interval64 { lo, hi }
+    }
+
+    /// Creates the interval `[x, x]`.
+    pub fn point(x: F64) -> Self {
+        Interval64 { lo: x, hi: x }
+    }
+
+    /// Returns whether neither bound is `NAN`, and `lo <= hi`.
+    pub fn is_valid(&self) -> bool {
+        !self.lo.0.is_nan() && !self.hi.0.is_nan() && self.lo <= self.hi
+    }
+
+    /// Returns whether `lo <= x <= hi`. This is always `false` for a `NAN` `x` (and for an
+    /// invalid interval).
+    pub fn contains(&self, x: F64) -> bool {
+        self.is_valid() && !x.0.is_nan() && self.lo <= x && x <= self.hi
+    }
+
+    /// Returns whether every value of `other` is contained in this interval. This is always
+    /// `false` if either interval is invalid.
+    pub fn contains_interval(&self, other: &Interval64) -> bool {
+        other.is_valid() && self.contains(other.lo) && self.contains(other.hi)
+    }
+
+    /// Returns `hi - lo`, rounded up.
+    pub fn width(&self) -> F64 {
+        F64(next_up(self.hi.0 - self.lo.0))
+    }
+
+    fn outward(lo: f64, hi: f64) -> Self {
+        Interval64::new(F64(next_down(lo)), F64(next_up(hi)))
+    }
+}
+
+/// Interval addition, rounded outward.
+impl Add for Interval64 {
+    type Output = Interval64;
+
+    fn add(self, other: Interval64) -> Interval64 {
+        Interval64::outward(self.lo.0 + other.lo.0, self.hi.0 + other.hi.0)
+    }
+}
+
+/// Interval subtraction, rounded outward.
+impl Sub for Interval64 {
+    type Output = Interval64;
+
+    fn sub(self, other: Interval64) -> Interval64 {
+        Interval64::outward(self.lo.0 - other.hi.0, self.hi.0 - other.lo.0)
+    }
+}
+
+/// Interval multiplication, rounded outward.
+impl Mul for Interval64 {
+    type Output = Interval64;
+
+    fn mul(self, other: Interval64) -> Interval64 {
+        let products = [
+            self.lo.0 * other.lo.0,
+            self.lo.0 * other.hi.0,
+            self.hi.0 * other.lo.0,
+            self.hi.0 * other.hi.0,
+        ];
+        if products.iter().any(|p| p.is_nan()) {
+            return Interval64::new(F64(f64::NAN), F64(f64::NAN));
+        }
+        let lo = products.iter().copied().map(F64).min().unwrap();
+        let hi = products.iter().copied().map(F64).max().unwrap();
+        Interval64::outward(lo.0, hi.0)
+    }
+}
+
+impl fmt::Display for Interval64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+/// Returns the least `f64` greater than `f`.
+fn next_up(f: f64) -> f64 {
+    if f.is_nan() || f == f64::INFINITY {
+        f
+    } else if f == 0.0 {
+        f64::from_bits(1)
+    } else if f > 0.0 {
+        f64::from_bits(f.to_bits() + 1)
+    } else {
+        f64::from_bits(f.to_bits() - 1)
+    }
+}
+
+/// Returns the greatest `f64` less than `f`.
+fn next_down(f: f64) -> f64 {
+    -next_up(-f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interval64;
+    use crate::F64;
+
+    fn interval(lo: f64, hi: f64) -> Interval64 {
+        Interval64::new(F64(lo), F64(hi))
+    }
+
+    #[test]
+    fn arithmetic() {
+        let sum = interval(1.0, 2.0) + interval(3.0, 4.0);
+        assert!(sum.contains_interval(&interval(4.0, 6.0)));
+        assert!(sum.lo < F64(4.0) && sum.hi > F64(6.0));
+
+        let difference = interval(1.0, 2.0) - interval(3.0, 4.0);
+        assert!(difference.contains_interval(&interval(-3.0, -1.0)));
+
+        let product = interval(-1.0, 2.0) * interval(3.0, 4.0);
+        assert!(product.contains_interval(&interval(-4.0, 8.0)));
+        assert!(!product.contains(F64(8.5)));
+
+        let tenth = Interval64::point(F64(0.1));
+        let sum = tenth + tenth + tenth;
+        assert!(sum.contains(F64(0.1 + 0.1 + 0.1)));
+        assert!(sum.width() > F64(0.0));
+    }
+
+    #[test]
+    fn validity() {
+        assert!(interval(1.0, 2.0).is_valid());
+        assert!(interval(2.0, 2.0).is_valid());
+        assert!(!interval(2.0, 1.0).is_valid());
+        assert!(!interval(f64::NAN, 1.0).is_valid());
+        assert!(!interval(0.0, f64::NAN).is_valid());
+        assert!(!(interval(f64::NAN, 1.0) + interval(0.0, 1.0)).is_valid());
+        assert!(!(interval(0.0, 1.0) * interval(1.0, f64::INFINITY)).is_valid());
+        assert!(!interval(f64::NAN, 1.0).contains(F64(0.5)));
+        assert!(!interval(0.0, 1.0).contains(F64(f64::NAN)));
+    }
+
+    #[test]
+    fn width() {
+        assert!(interval(1.0, 3.0).width() >= F64(2.0));
+        assert!(interval(1.0, 3.0).width() < F64(2.0 + 1e-15));
+    }
+}
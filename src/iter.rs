@@ -0,0 +1,141 @@
+//! `EqFloatIteratorExt`, which finds the least and greatest elements of an iterator over `F32` or
+//! `F64` while skipping `NAN`s.
+
+use crate::{F32, F64};
+
+/// Extension methods for iterators over `F32` or `F64`, for when `NAN` should be excluded rather
+/// than dominate the order (`Iterator::min` on a sequence containing a `NAN` simply returns that
+/// `NAN`).
+pub trait EqFloatIteratorExt<T>: Iterator<Item = T> {
+    /// Returns the least element that is not `NAN`, or `None` if there is no such element.
+    fn min_ignoring_nan(self) -> Option<T>;
+
+    /// Returns the greatest element that is not `NAN`, or `None` if there is no such element.
+    fn max_ignoring_nan(self) -> Option<T>;
+
+    /// Returns the least element that is not `NAN`, and whether any `NAN` was encountered.
+    fn min_with_nan_flag(self) -> (Option<T>, bool);
+
+    /// Returns the greatest element that is not `NAN`, and whether any `NAN` was encountered.
+    fn max_with_nan_flag(self) -> (Option<T>, bool);
+}
+
+impl<I: Iterator<Item = F32>> EqFloatIteratorExt<F32> for I {
+    fn min_ignoring_nan(self) -> Option<F32> {
+        self.min_with_nan_flag().0
+    }
+
+    fn max_ignoring_nan(self) -> Option<F32> {
+        self.max_with_nan_flag().0
+    }
+
+    fn min_with_nan_flag(self) -> (Option<F32>, bool) {
+        let mut found_nan = false;
+        let min = self
+            .filter(|f| {
+                found_nan |= f.0.is_nan();
+                !f.0.is_nan()
+            })
+            .min();
+        (min, found_nan)
+    }
+
+    fn max_with_nan_flag(self) -> (Option<F32>, bool) {
+        let mut found_nan = false;
+        let max = self
+            .filter(|f| {
+                found_nan |= f.0.is_nan();
+                !f.0.is_nan()
+            })
+            .max();
+        (max, found_nan)
+    }
+}
+
+impl<I: Iterator<Item = F64>> EqFloatIteratorExt<F64> for I {
+    fn min_ignoring_nan(self) -> Option<F64> {
+        self.min_with_nan_flag().0
+    }
+
+    fn max_ignoring_nan(self) -> Option<F64> {
+        self.max_with_nan_flag().0
+    }
+
+    fn min_with_nan_flag(self) -> (Option<F64>, bool) {
+        let mut found_nan = false;
+        let min = self
+            .filter(|f| {
+                found_nan |= f.0.is_nan();
+                !f.0.is_nan()
+            })
+            .min();
+        (min, found_nan)
+    }
+
+    fn max_with_nan_flag(self) -> (Option<F64>, bool) {
+        let mut found_nan = false;
+        let max = self
+            .filter(|f| {
+                found_nan |= f.0.is_nan();
+                !f.0.is_nan()
+            })
+            .max();
+        (max, found_nan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EqFloatIteratorExt;
+    use crate::{F32, F64};
+
+    #[test]
+    fn f32_ignoring_nan() {
+        let values = [F32(2.0), F32(f32::NAN), F32(-1.0), F32(5.0), F32(f32::NAN)];
+        assert_eq!(values.iter().copied().min(), Some(F32(f32::NAN)));
+        assert_eq!(values.iter().copied().min_ignoring_nan(), Some(F32(-1.0)));
+        assert_eq!(values.iter().copied().max_ignoring_nan(), Some(F32(5.0)));
+        assert_eq!(
+            values.iter().copied().min_with_nan_flag(),
+            (Some(F32(-1.0)), true)
+        );
+        assert_eq!(
+            values.iter().copied().max_with_nan_flag(),
+            (Some(F32(5.0)), true)
+        );
+        assert_eq!(
+            values[..1].iter().copied().max_with_nan_flag(),
+            (Some(F32(2.0)), false)
+        );
+        assert_eq!(
+            vec![F32(f32::NAN)].into_iter().max_with_nan_flag(),
+            (None, true)
+        );
+        assert_eq!(Vec::<F32>::new().into_iter().min_ignoring_nan(), None);
+    }
+
+    #[test]
+    fn f64_ignoring_nan() {
+        let values = [F64(2.0), F64(f64::NAN), F64(-1.0), F64(5.0), F64(f64::NAN)];
+        assert_eq!(values.iter().copied().min(), Some(F64(f64::NAN)));
+        assert_eq!(values.iter().copied().min_ignoring_nan(), Some(F64(-1.0)));
+        assert_eq!(values.iter().copied().max_ignoring_nan(), Some(F64(5.0)));
+        assert_eq!(
+            values.iter().copied().min_with_nan_flag(),
+            (Some(F64(-1.0)), true)
+        );
+        assert_eq!(
+            values.iter().copied().max_with_nan_flag(),
+            (Some(F64(5.0)), true)
+        );
+        assert_eq!(
+            values[..1].iter().copied().max_with_nan_flag(),
+            (Some(F64(2.0)), false)
+        );
+        assert_eq!(
+            vec![F64(f64::NAN)].into_iter().max_with_nan_flag(),
+            (None, true)
+        );
+        assert_eq!(Vec::<F64>::new().into_iter().min_ignoring_nan(), None);
+    }
+}
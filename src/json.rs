@@ -0,0 +1,132 @@
+//! Conversions between `F64` and `serde_json::Value`.
+//!
+//! JSON has no representation for `NAN` and the infinities, so the policy is explicit:
+//!
+//! - `Value::try_from(F64)` fails for non-finite values, and `F64::to_json_lossy` maps them to
+//!   `Value::Null` instead.
+//! - `F64::try_from(&Value)` accepts exactly the JSON numbers, converting integers that are too
+//!   large for an `f64` to represent exactly (such as `u64` values above 2^53) to the nearest
+//!   `f64`. `F64::from_json_null_as_nan` additionally accepts `null` as `NAN`, which undoes
+//!   `to_json_lossy` for `NAN` (but not for the infinities).
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use serde_json::{Number, Value};
+
+use crate::F64;
+
+/// The error returned by the conversions between `F64` and `serde_json::Value`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonError {
+    /// The float is `NAN` or infinite, and can thus not be a JSON number.
+    NonFinite,
+    /// The JSON value is not a number.
+    NotANumber,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonError::NonFinite => write!(f, "non-finite float can not be a JSON number"),
+            JsonError::NotANumber => write!(f, "JSON value is not a number"),
+        }
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+/// Fails with `JsonError::NonFinite` for `NAN` and the infinities.
+impl TryFrom<F64> for Value {
+    type Error = JsonError;
+
+    fn try_from(f: F64) -> Result<Self, Self::Error> {
+        Number::from_f64(f.0)
+            .map(Value::Number)
+            .ok_or(JsonError::NonFinite)
+    }
+}
+
+/// Accepts JSON numbers only, converting them to the nearest `f64`.
+impl TryFrom<&Value> for F64 {
+    type Error = JsonError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        value.as_f64().map(F64).ok_or(JsonError::NotANumber)
+    }
+}
+
+impl F64 {
+    /// Converts to a JSON number, or to `Value::Null` for `NAN` and the infinities.
+    pub fn to_json_lossy(self) -> Value {
+        Value::try_from(self).unwrap_or(Value::Null)
+    }
+
+    /// Converts a JSON number like `F64::try_from(&Value)` does, but also accepts `null` as
+    /// `NAN`.
+    pub fn from_json_null_as_nan(value: &Value) -> Result<F64, JsonError> {
+        match value {
+            Value::Null => Ok(F64(f64::NAN)),
+            _ => F64::try_from(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use serde_json::{json, Value};
+
+    use super::JsonError;
+    use crate::F64;
+
+    #[test]
+    fn to_json() {
+        assert_eq!(Value::try_from(F64(1.5)), Ok(json!(1.5)));
+        assert_eq!(Value::try_from(F64(f64::NAN)), Err(JsonError::NonFinite));
+        assert_eq!(
+            Value::try_from(F64(f64::INFINITY)),
+            Err(JsonError::NonFinite)
+        );
+
+        assert_eq!(F64(-2.0).to_json_lossy(), json!(-2.0));
+        assert_eq!(F64(f64::NAN).to_json_lossy(), Value::Null);
+        assert_eq!(F64(f64::NEG_INFINITY).to_json_lossy(), Value::Null);
+    }
+
+    #[test]
+    fn from_json() {
+        assert_eq!(F64::try_from(&json!(1.5)), Ok(F64(1.5)));
+        assert_eq!(F64::try_from(&json!(-7)), Ok(F64(-7.0)));
+        assert_eq!(
+            F64::try_from(&json!(u64::MAX)),
+            Ok(F64(18_446_744_073_709_551_616.0))
+        );
+        assert_eq!(
+            F64::try_from(&json!((1u64 << 53) + 1)),
+            Ok(F64((1u64 << 53) as f64))
+        );
+        assert_eq!(F64::try_from(&Value::Null), Err(JsonError::NotANumber));
+        assert_eq!(F64::try_from(&json!("1.5")), Err(JsonError::NotANumber));
+
+        assert_eq!(F64::from_json_null_as_nan(&Value::Null), Ok(F64(f64::NAN)));
+        assert_eq!(F64::from_json_null_as_nan(&json!(0.25)), Ok(F64(0.25)));
+        assert_eq!(
+            F64::from_json_null_as_nan(&json!([])),
+            Err(JsonError::NotANumber)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for f in [0.0, -0.0, 0.1, -123.456, 1e300, f64::MIN_POSITIVE] {
+            let value = Value::try_from(F64(f)).unwrap();
+            let back = F64::try_from(&value).unwrap();
+            assert_eq!(back.0.to_bits(), f.to_bits());
+        }
+
+        let nan = F64(f64::NAN).to_json_lossy();
+        assert_eq!(F64::from_json_null_as_nan(&nan), Ok(F64(f64::NAN)));
+    }
+}
@@ -0,0 +1,117 @@
+//! Linear interpolation: `lerp`, its inverse `inverse_lerp`, and `remap`, which maps a value from
+//! one range to another.
+
+use crate::{F32, F64};
+
+impl F32 {
+    /// Interpolates linearly between `self` (at `t == 0.0`) and `other` (at `t == 1.0`), as
+    /// `self + (other - self) * t`. Values of `t` outside `[0, 1]` extrapolate along the same
+    /// line. The result is `NAN` if any input is `NAN`.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * F32(t)
+    }
+
+    /// Returns the `t` for which `self.lerp(other, t)` is `value`, as
+    /// `(value - self) / (other - self)`. It is outside `[0, 1]` if `value` is not between
+    /// `self` and `other`. The result is `NAN` if any input is `NAN`, and non-finite if `self`
+    /// equals `other`.
+    pub fn inverse_lerp(self, other: Self, value: Self) -> Self {
+        (value - self) / (other - self)
+    }
+
+    /// Maps `self` from the range between `in_lo` and `in_hi` to the corresponding position
+    /// between `out_lo` and `out_hi`, extrapolating for values outside the input range. The
+    /// result is `NAN` if any input is `NAN`, and non-finite if `in_lo` equals `in_hi`.
+    pub fn remap(self, in_lo: Self, in_hi: Self, out_lo: Self, out_hi: Self) -> Self {
+        out_lo.lerp(out_hi, in_lo.inverse_lerp(in_hi, self).0)
+    }
+}
+
+impl F64 {
+    /// Interpolates linearly between `self` (at `t == 0.0`) and `other` (at `t == 1.0`), as
+    /// `self + (other - self) * t`. Values of `t` outside `[0, 1]` extrapolate along the same
+    /// line. The result is `NAN` if any input is `NAN`.
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        self + (other - self) * F64(t)
+    }
+
+    /// Returns the `t` for which `self.lerp(other, t)` is `value`, as
+    /// `(value - self) / (other - self)`. It is outside `[0, 1]` if `value` is not between
+    /// `self` and `other`. The result is `NAN` if any input is `NAN`, and non-finite if `self`
+    /// equals `other`.
+    pub fn inverse_lerp(self, other: Self, value: Self) -> Self {
+        (value - self) / (other - self)
+    }
+
+    /// Maps `self` from the range between `in_lo` and `in_hi` to the corresponding position
+    /// between `out_lo` and `out_hi`, extrapolating for values outside the input range. The
+    /// result is `NAN` if any input is `NAN`, and non-finite if `in_lo` equals `in_hi`.
+    pub fn remap(self, in_lo: Self, in_hi: Self, out_lo: Self, out_hi: Self) -> Self {
+        out_lo.lerp(out_hi, in_lo.inverse_lerp(in_hi, self).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f32_lerp() {
+        let (a, b) = (F32(2.0), F32(6.0));
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), F32(4.0));
+        assert_eq!(a.lerp(b, 1.5), F32(8.0));
+        assert_eq!(a.lerp(b, -0.25), F32(1.0));
+        assert_eq!(a.lerp(b, f32::NAN), F32(f32::NAN));
+        assert_eq!(a.lerp(F32(f32::NAN), 0.0), F32(f32::NAN));
+
+        assert_eq!(a.inverse_lerp(b, F32(4.0)), F32(0.5));
+        assert_eq!(a.inverse_lerp(b, a), F32(0.0));
+        assert_eq!(a.inverse_lerp(b, F32(10.0)), F32(2.0));
+        assert_eq!(a.inverse_lerp(a, a), F32(f32::NAN));
+
+        assert_eq!(
+            F32(5.0).remap(F32(0.0), F32(10.0), F32(100.0), F32(200.0)),
+            F32(150.0)
+        );
+        assert_eq!(
+            F32(-5.0).remap(F32(0.0), F32(10.0), F32(200.0), F32(100.0)),
+            F32(250.0)
+        );
+        assert_eq!(
+            F32(f32::NAN).remap(F32(0.0), F32(1.0), F32(0.0), F32(1.0)),
+            F32(f32::NAN)
+        );
+    }
+
+    #[test]
+    fn f64_lerp() {
+        let (a, b) = (F64(2.0), F64(6.0));
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), F64(4.0));
+        assert_eq!(a.lerp(b, 1.5), F64(8.0));
+        assert_eq!(a.lerp(b, -0.25), F64(1.0));
+        assert_eq!(a.lerp(b, f64::NAN), F64(f64::NAN));
+        assert_eq!(a.lerp(F64(f64::NAN), 0.0), F64(f64::NAN));
+
+        assert_eq!(a.inverse_lerp(b, F64(4.0)), F64(0.5));
+        assert_eq!(a.inverse_lerp(b, a), F64(0.0));
+        assert_eq!(a.inverse_lerp(b, F64(10.0)), F64(2.0));
+        assert_eq!(a.inverse_lerp(a, a), F64(f64::NAN));
+
+        assert_eq!(
+            F64(5.0).remap(F64(0.0), F64(10.0), F64(100.0), F64(200.0)),
+            F64(150.0)
+        );
+        assert_eq!(
+            F64(-5.0).remap(F64(0.0), F64(10.0), F64(200.0), F64(100.0)),
+            F64(250.0)
+        );
+        assert_eq!(
+            F64(f64::NAN).remap(F64(0.0), F64(1.0), F64(0.0), F64(1.0)),
+            F64(f64::NAN)
+        );
+    }
+}
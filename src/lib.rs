@@ -1,18 +1,487 @@
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "nightly-float", feature(f16, f128))]
 
-#[derive(Debug, Default, Clone, Copy)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::std_traits::ReflectDefault;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(target_has_atomic = "32")]
+mod atomic;
+mod bit_eq;
+#[cfg(feature = "alloc")]
+pub mod btree;
+mod cmp;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+#[cfg(feature = "decorum")]
+mod decorum;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "alloc")]
+mod delta;
+#[cfg(feature = "diesel")]
+mod diesel_sql;
+mod ext;
+#[cfg(feature = "half")]
+mod float16;
+mod generic;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+mod hex;
+mod int;
+mod interval;
+mod iter;
+#[cfg(feature = "json")]
+mod json;
+mod lerp;
+mod linalg;
+#[macro_use]
+mod macros;
+#[doc(hidden)]
+pub use macros::__private;
+mod nan_high;
+mod narrow;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+#[cfg(feature = "nightly-float")]
+pub mod nightly;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "ordered-float")]
+mod ordered_float;
+mod parse;
+pub mod prelude;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+pub mod rand;
+mod range;
+#[cfg(feature = "rayon")]
+pub mod rayon;
+#[cfg(feature = "std")]
+mod round;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "ryu")]
+mod ryu;
+pub mod search;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod sign;
+#[cfg(feature = "simba")]
+mod simba;
+#[cfg(feature = "simd")]
+mod simd;
+mod slice;
+mod sort;
+mod split_zero;
+#[cfg(feature = "speedy")]
+mod speedy;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+pub mod stats;
+mod sum;
+mod total;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm_bindgen;
+
+#[cfg(target_has_atomic = "32")]
+pub use atomic::AtomicF32;
+#[cfg(target_has_atomic = "64")]
+pub use atomic::AtomicF64;
+pub use bit_eq::{BitEq32, BitEq64};
+pub use cmp::{total_cmp_f32, total_cmp_f64, total_eq_f32, total_eq_f64};
+#[cfg(feature = "rust_decimal")]
+pub use decimal::DecimalError;
+#[cfg(feature = "alloc")]
+pub use delta::{delta_decode, delta_encode};
+#[cfg(feature = "half")]
+pub use float16::F16;
+pub use ext::{EqFloatExt, IntoEqFloat};
+pub use generic::{CanonicalBits, EqFloat};
+pub use hex::ParseHexFloatError;
+pub use int::InexactIntError;
+pub use interval::Interval64;
+pub use iter::EqFloatIteratorExt;
+#[cfg(feature = "json")]
+pub use json::JsonError;
+pub use linalg::dot;
+#[cfg(feature = "std")]
+pub use linalg::norm2;
+pub use nan_high::{F32NanHigh, F64NanHigh, NanHigh32, NanHigh64};
+pub use narrow::{NarrowingError, RoundMode};
+pub use parse::ParseError;
+pub use range::RangeError;
+pub use sign::SignClass;
+#[cfg(feature = "simd")]
+pub use simd::{
+    simd_sum_f32, simd_sum_f64, simd_total_max_f32, simd_total_max_f64, simd_total_min_f32,
+    simd_total_min_f64,
+};
+pub use split_zero::{SplitZero32, SplitZero64};
+pub use sum::{checked_sum, SumError};
+pub use total::{TotalF32, TotalF64, TotalOrd32, TotalOrd64};
+
+/// The bits of the `f32` `NAN` that stands in for all `NAN`s wherever this crate needs a single
+/// representation, e.g. for hashing.
+pub const CANONICAL_NAN_BITS_F32: u32 = 0x7fc00000;
+
+/// The bits of the `f64` `NAN` that stands in for all `NAN`s wherever this crate needs a single
+/// representation, e.g. for hashing.
+pub const CANONICAL_NAN_BITS_F64: u64 = 0x7ff8000000000000;
+
+#[derive(Default, Clone, Copy)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::FromZeroes, zerocopy::FromBytes, zerocopy::AsBytes)
+)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect_value(Debug, Default, PartialEq, Hash, Serialize, Deserialize)
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(::diesel::AsExpression, ::diesel::FromSqlRow),
+    diesel(sql_type = ::diesel::sql_types::Float)
+)]
+#[repr(transparent)]
 pub struct F32(pub f32);
 
+impl F32 {
+    /// `f32::NAN`. All `NAN` are equal, so this is equal to any other `NAN`.
+    pub const NAN: Self = F32(f32::NAN);
+
+    /// Positive infinity.
+    pub const INFINITY: Self = F32(f32::INFINITY);
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = F32(f32::NEG_INFINITY);
+
+    /// Returns `f32::NAN`, for use where a literal is awkward (such as a default other than
+    /// the `0.0` of `Default`).
+    pub const fn nan() -> Self {
+        F32(f32::NAN)
+    }
+
+    /// Returns positive infinity.
+    pub const fn infinity() -> Self {
+        F32(f32::INFINITY)
+    }
+
+    /// Returns negative infinity.
+    pub const fn neg_infinity() -> Self {
+        F32(f32::NEG_INFINITY)
+    }
+
+    /// Returns the `NAN` whose bits are `CANONICAL_NAN_BITS_F32`.
+    pub const fn canonical_nan() -> Self {
+        F32(f32::from_bits(CANONICAL_NAN_BITS_F32))
+    }
+
+    /// Wraps a float like `From` does, but panics if it is `NAN` and debug assertions are
+    /// enabled. This is a development aid for catching accidental `NAN`s early, not a guarantee:
+    /// in release builds, `NAN` is wrapped without complaint.
+    #[track_caller]
+    pub fn from_checked(value: f32) -> Self {
+        debug_assert!(!value.is_nan(), "F32::from_checked called with NAN");
+        F32(value)
+    }
+
+    /// Returns the inner float if it is not `NAN`, and `None` otherwise.
+    pub fn not_nan(self) -> Option<f32> {
+        if self.0.is_nan() {
+            None
+        } else {
+            Some(self.0)
+        }
+    }
+
+    /// Returns the inner float, panicking with the given message if it is `NAN`.
+    pub fn expect_not_nan(self, msg: &str) -> f32 {
+        self.not_nan().expect(msg)
+    }
+
+    /// Returns the inner float.
+    pub const fn as_f32(self) -> f32 {
+        self.0
+    }
+
+    /// Returns the inner float widened to an `f64`. This is lossless, but note that the result
+    /// is generally not the `f64` closest to the decimal literal the `f32` was written as
+    /// (`F32(0.1).as_f64() != 0.1`).
+    pub fn as_f64(self) -> f64 {
+        f64::from(self.0)
+    }
+
+    /// Returns the memory representation of the inner float in big-endian byte order.
+    pub fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the memory representation of the inner float in little-endian byte order.
+    pub fn to_le_bytes(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns the memory representation of the inner float in native byte order.
+    pub fn to_ne_bytes(self) -> [u8; 4] {
+        self.0.to_ne_bytes()
+    }
+
+    /// Creates a value from its memory representation in big-endian byte order.
+    pub fn from_be_bytes(bytes: [u8; 4]) -> Self {
+        F32(f32::from_be_bytes(bytes))
+    }
+
+    /// Creates a value from its memory representation in little-endian byte order.
+    pub fn from_le_bytes(bytes: [u8; 4]) -> Self {
+        F32(f32::from_le_bytes(bytes))
+    }
+
+    /// Creates a value from its memory representation in native byte order.
+    pub fn from_ne_bytes(bytes: [u8; 4]) -> Self {
+        F32(f32::from_ne_bytes(bytes))
+    }
+
+    /// Returns big-endian bytes whose lexicographic order matches the order of this type: all
+    /// `NAN` encode to the smallest bytes, and both zeros encode to the same bytes.
+    pub fn to_sortable_bytes(self) -> [u8; 4] {
+        self.to_ordered_bits().to_be_bytes()
+    }
+
+    /// Decodes bytes produced by `to_sortable_bytes`. This returns `0.0` for either zero and
+    /// `f32::NAN` for any `NAN`.
+    pub fn from_sortable_bytes(bytes: [u8; 4]) -> Self {
+        F32::from_ordered_bits(u32::from_be_bytes(bytes))
+    }
+
+    /// Returns an integer whose order matches the order of this type: all `NAN`s map to `0`,
+    /// and both zeros map to `0x8000_0000`. Otherwise, this flips the sign bit of non-negative
+    /// values and all bits of negative values.
+    pub const fn to_ordered_bits(self) -> u32 {
+        ordered_key_f32(self.0.to_bits())
+    }
+
+    /// Inverts `to_ordered_bits`, returning `0.0` for either zero and `f32::NAN` for any `NAN`.
+    /// The integers that `to_ordered_bits` never returns decode to a `NAN` (with some sign and
+    /// payload), except for `!0x8000_0000`, which decodes to `-0.0`.
+    pub fn from_ordered_bits(key: u32) -> Self {
+        if key & 0x8000_0000 != 0 {
+            F32(f32::from_bits(key & !0x8000_0000))
+        } else if key == 0 {
+            F32(f32::NAN)
+        } else {
+            F32(f32::from_bits(!key))
+        }
+    }
+
+    /// Maps `NAN` to `0.0`, positive infinity to `f32::MAX` and negative infinity to
+    /// `f32::MIN`, leaving finite values untouched.
+    pub fn clamp_to_finite(self) -> Self {
+        self.clamp_to_finite_or(F32(0.0))
+    }
+
+    /// Maps `NAN` to `nan_default`, positive infinity to `f32::MAX` and negative infinity to
+    /// `f32::MIN`, leaving finite values untouched.
+    pub fn clamp_to_finite_or(self, nan_default: Self) -> Self {
+        if self.0.is_nan() {
+            nan_default
+        } else if self.0 == f32::INFINITY {
+            F32(f32::MAX)
+        } else if self.0 == f32::NEG_INFINITY {
+            F32(f32::MIN)
+        } else {
+            self
+        }
+    }
+
+    /// Adds `other`, clamping a result that overflowed to infinity to `f32::MAX` or `f32::MIN`.
+    /// Infinite operands still yield infinite results, and `NAN` still yields `NAN`.
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.saturate(other, self.0 + other.0)
+    }
+
+    /// Subtracts `other`, clamping a result that overflowed to infinity to `f32::MAX` or
+    /// `f32::MIN`. Infinite operands still yield infinite results, and `NAN` still yields `NAN`.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self.saturate(other, self.0 - other.0)
+    }
+
+    /// Multiplies by `other`, clamping a result that overflowed to infinity to `f32::MAX` or
+    /// `f32::MIN`. Infinite operands still yield infinite results, and `NAN` still yields `NAN`.
+    pub fn saturating_mul(self, other: Self) -> Self {
+        self.saturate(other, self.0 * other.0)
+    }
+
+    fn saturate(self, other: Self, result: f32) -> Self {
+        if result.is_infinite() && self.0.is_finite() && other.0.is_finite() {
+            F32(result).clamp_to_finite()
+        } else {
+            F32(result)
+        }
+    }
+
+    /// Maps subnormal values (of either sign) to `0.0`, leaving all other values untouched.
+    ///
+    /// Since `0.0 == -0.0` already holds for this type, the sign of a flushed value would not
+    /// affect equality, ordering or hashing anyway; it is normalized to positive zero regardless.
+    pub fn flush_subnormals(self) -> Self {
+        if self.0.is_subnormal() {
+            F32(0.0)
+        } else {
+            self
+        }
+    }
+
+    /// Returns `None` for `0.0` and `-0.0` (which are equal), and `Some(self)` otherwise.
+    pub fn nonzero(self) -> Option<Self> {
+        if self.0 == 0.0 {
+            None
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Returns the wrapped value, or `0.0` for `None`. This undoes `nonzero`, except that it
+    /// always yields positive zero.
+    pub fn or_zero(value: Option<Self>) -> Self {
+        value.unwrap_or(F32(0.0))
+    }
+
+    /// Returns how many steps from one representable float to the next it takes to get from
+    /// `self` to `other` (in either direction), or `None` if either is `NAN`. The two zeros count
+    /// as a single float, so the distance between the least positive and the greatest negative
+    /// float is `2`.
+    pub fn ulps_between(self, other: Self) -> Option<u64> {
+        if self.0.is_nan() || other.0.is_nan() {
+            return None;
+        }
+
+        let (a, b) = (self.0.to_bits(), other.0.to_bits());
+        let a_magnitude = u64::from(a & !0x8000_0000);
+        let b_magnitude = u64::from(b & !0x8000_0000);
+        if a_magnitude == 0 || b_magnitude == 0 || (a & 0x8000_0000) == (b & 0x8000_0000) {
+            Some(a_magnitude.abs_diff(b_magnitude))
+        } else {
+            Some(a_magnitude + b_magnitude)
+        }
+    }
+
+    /// Feeds `self.0.to_bits()` into the hasher, without the canonicalization `Hash` does. Use
+    /// this for hashing that distinguishes all bit patterns; it is not consistent with `Eq`.
+    pub fn raw_bits_hash<H: Hasher>(self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+
+    /// Returns a hash of the value that does not depend on any `Hasher`, and that is stable
+    /// across crate versions and platforms. Equal values have equal hashes.
+    ///
+    /// This is the 64 bit FNV-1a hash of the little-endian bytes of the bits of the value, where
+    /// all `NAN`s are represented by `CANONICAL_NAN_BITS_F32` and both zeros by `0`.
+    pub fn stable_hash64(self) -> u64 {
+        fnv1a64(&self.canonical_bits().to_le_bytes())
+    }
+
+    /// Returns the key that identifies the value for hashing (the `Hash` implementation feeds
+    /// the `u32` this is widened from). This is injective on the equivalence classes of `Eq`: two
+    /// values have the same key if and only if they are equal. All `NAN` share the bits of
+    /// `f32::NAN` as their key, and both zeros share the key `0`; every other value has its bit
+    /// representation as its key.
+    pub fn hash_key(self) -> u64 {
+        u64::from(self.canonical_bits())
+    }
+
+    /// Compares like `Ord::cmp` does, but can be evaluated in `const` contexts.
+    pub const fn const_cmp(a: Self, b: Self) -> Ordering {
+        let (a, b) = (a.to_ordered_bits(), b.to_ordered_bits());
+        if a < b {
+            Ordering::Less
+        } else if a > b {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Compares like `PartialEq::eq` does, but can be evaluated in `const` contexts.
+    pub const fn const_eq(a: Self, b: Self) -> bool {
+        a.to_ordered_bits() == b.to_ordered_bits()
+    }
+
+    // The bits that get hashed: a particular bit representation for all NAN, and the same
+    // bits for both positive and negative zero.
+    // This is branch-free, selecting the result with masks.
+    fn canonical_bits(self) -> u32 {
+        let bits = self.0.to_bits();
+        let magnitude = bits & !0x8000_0000;
+        let nan = ((magnitude > 0x7f80_0000) as u32).wrapping_neg();
+        let zero = ((magnitude == 0) as u32).wrapping_neg();
+        (bits & !zero & !nan) | (CANONICAL_NAN_BITS_F32 & nan)
+    }
+
+    /// Returns a value whose `Debug` output tags the special cases, e.g. `F32(-0.0 [neg_zero])`,
+    /// `F32(NaN)`, `F32(inf)` or `F32(1e-40 [subnormal])`, while normal values print as
+    /// `F32(1.5)`. `Debug` on `F32` itself prints just the inner float.
+    pub fn classified(self) -> impl fmt::Debug {
+        ClassifiedF32(self.0)
+    }
+
+    /// Formats the value like `Display`, except that it always prints `-0` for negative zero
+    /// and `NaN` for any `NAN` (regardless of sign and payload), so that the special cases of
+    /// this crate are unambiguous in logs.
+    #[cfg(feature = "alloc")]
+    pub fn to_debug_string(self) -> String {
+        if self.0.is_nan() {
+            "NaN".to_string()
+        } else if self.0 == 0.0 && self.0.is_sign_negative() {
+            "-0".to_string()
+        } else {
+            self.0.to_string()
+        }
+    }
+}
+
+/// Maps the bits of a float to an integer with the same order as `F32`, see
+/// `F32::to_ordered_bits`. This is branch-free, so that comparisons compile to a few integer
+/// instructions.
+const fn ordered_key_f32(bits: u32) -> u32 {
+    let magnitude = bits & !0x8000_0000;
+    let negative = (bits as i32 >> 31) as u32;
+    // Flips all bits of negative values, and only the sign bit of the others.
+    let key = bits ^ (negative | 0x8000_0000);
+    // Negative zero now sits right below positive zero, so moves up to it.
+    let key = key + ((magnitude == 0) & (negative != 0)) as u32;
+    // All `NAN`s map to `0`, which no other value maps to.
+    key & ((magnitude <= 0x7f80_0000) as u32).wrapping_neg()
+}
+
 /// This works like `PartialEq` on `f32`, except that `NAN == NAN` is true.
 impl PartialEq for F32 {
     fn eq(&self, other: &Self) -> bool {
-        if self.0.is_nan() && other.0.is_nan() {
-            true
-        } else {
-            self.0 == other.0
-        }
+        F32::const_eq(*self, *other)
     }
 }
 
@@ -30,26 +499,31 @@ impl PartialOrd for F32 {
 /// (and is equal to another NAN).
 impl Ord for F32 {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.0.partial_cmp(&other.0).unwrap_or_else(|| {
-            if self.0.is_nan() && !other.0.is_nan() {
-                Ordering::Less
-            } else if !self.0.is_nan() && other.0.is_nan() {
-                Ordering::Greater
-            } else {
-                Ordering::Equal
-            }
-        })
+        F32::const_cmp(*self, *other)
     }
 }
 
+/// Hashes exactly like `self.0.to_bits()` does, except that all `NAN`s hash like
+/// `CANONICAL_NAN_BITS_F32` and negative zero hashes like positive zero. This is guaranteed, so
+/// `Hash` is compatible with hashing the bits of a non-`NAN`, nonzero float directly.
+///
+/// Slices are hashed by writing all their canonical bits in bulk, which matches hashing the
+/// elements one by one only for hashers that treat their input as a byte stream (like the
+/// default `SipHash`).
 impl Hash for F32 {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        if self.0.is_nan() {
-            0x7fc00000u32.hash(state); // a particular bit representation for NAN
-        } else if self.0 == 0.0 { // catches both positive and negative zero
-            0u32.hash(state);
-        } else {
-            self.0.to_bits().hash(state);
+        self.canonical_bits().hash(state);
+    }
+
+    // Feeds the canonical bits of many values to the hasher at once, which is faster for
+    // hashers that process large inputs in bulk.
+    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H) {
+        let mut buf = [0u8; 256];
+        for chunk in data.chunks(64) {
+            for (f, bytes) in chunk.iter().zip(buf.chunks_exact_mut(4)) {
+                bytes.copy_from_slice(&f.canonical_bits().to_ne_bytes());
+            }
+            state.write(&buf[..chunk.len() * 4]);
         }
     }
 }
@@ -66,136 +540,1654 @@ impl From<f32> for F32 {
     }
 }
 
+struct ClassifiedF32(f32);
+
+impl fmt::Debug for ClassifiedF32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_nan() {
+            write!(f, "F32(NaN)")
+        } else if self.0.is_infinite() {
+            write!(f, "F32({})", self.0)
+        } else if self.0 == 0.0 && self.0.is_sign_negative() {
+            write!(f, "F32(-0.0 [neg_zero])")
+        } else if self.0.is_subnormal() {
+            write!(f, "F32({:?} [subnormal])", self.0)
+        } else {
+            write!(f, "F32({:?})", self.0)
+        }
+    }
+}
+
+/// Formats exactly like the wrapped `f32`, so `F32(1.0)` prints as `1.0` rather than `F32(1.0)`.
+impl fmt::Debug for F32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
 impl fmt::Display for F32 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
-#[derive(Debug, Default, Clone, Copy)]
-pub struct F64(pub f64);
+impl fmt::LowerExp for F32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.0, f)
+    }
+}
 
-/// This works like `PartialEq` on `f64`, except that `NAN == NAN` is true.
-impl PartialEq for F64 {
-    fn eq(&self, other: &Self) -> bool {
-        if self.0.is_nan() && other.0.is_nan() {
-            true
-        } else {
-            self.0 == other.0
-        }
+impl fmt::UpperExp for F32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperExp::fmt(&self.0, f)
     }
 }
 
-impl Eq for F64 {}
+/// Formats the bits of the representation (not the value), zero-padded to 8 digits. The
+/// alternate flag adds a `0x` prefix.
+impl fmt::LowerHex for F32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_bits(f, self.0.to_bits().into(), 32, 4, "0x", b"0123456789abcdef")
+    }
+}
 
-/// This works like `PartialOrd` on `f64`, except that `NAN` sorts below all other floats
-/// (and is equal to another NAN). This always returns a `Some`.
-impl PartialOrd for F64 {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+/// Formats the bits of the representation (not the value), zero-padded to 8 digits. The
+/// alternate flag adds a `0x` prefix.
+impl fmt::UpperHex for F32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_bits(f, self.0.to_bits().into(), 32, 4, "0x", b"0123456789ABCDEF")
     }
 }
 
-/// This works like `PartialOrd` on `f64`, except that `NAN` sorts below all other floats
-/// (and is equal to another NAN).
-impl Ord for F64 {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.0.partial_cmp(&other.0).unwrap_or_else(|| {
-            if self.0.is_nan() && !other.0.is_nan() {
-                Ordering::Less
-            } else if !self.0.is_nan() && other.0.is_nan() {
-                Ordering::Greater
-            } else {
-                Ordering::Equal
-            }
-        })
+/// Formats the bits of the representation (not the value), zero-padded to 32 digits. The
+/// alternate flag adds a `0b` prefix.
+impl fmt::Binary for F32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_bits(f, self.0.to_bits().into(), 32, 1, "0b", b"01")
     }
 }
 
-impl Hash for F64 {
-    fn hash<H: Hasher>(&self, state: &mut H) {
+#[derive(Default, Clone, Copy)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::FromZeroes, zerocopy::FromBytes, zerocopy::AsBytes)
+)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect_value(Debug, Default, PartialEq, Hash, Serialize, Deserialize)
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(::diesel::AsExpression, ::diesel::FromSqlRow),
+    diesel(sql_type = ::diesel::sql_types::Double)
+)]
+#[repr(transparent)]
+pub struct F64(pub f64);
+
+impl F64 {
+    /// `f64::NAN`. All `NAN` are equal, so this is equal to any other `NAN`.
+    pub const NAN: Self = F64(f64::NAN);
+
+    /// Positive infinity.
+    pub const INFINITY: Self = F64(f64::INFINITY);
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = F64(f64::NEG_INFINITY);
+
+    /// Returns `f64::NAN`, for use where a literal is awkward (such as a default other than
+    /// the `0.0` of `Default`).
+    pub const fn nan() -> Self {
+        F64(f64::NAN)
+    }
+
+    /// Returns positive infinity.
+    pub const fn infinity() -> Self {
+        F64(f64::INFINITY)
+    }
+
+    /// Returns negative infinity.
+    pub const fn neg_infinity() -> Self {
+        F64(f64::NEG_INFINITY)
+    }
+
+    /// Returns the `NAN` whose bits are `CANONICAL_NAN_BITS_F64`.
+    pub const fn canonical_nan() -> Self {
+        F64(f64::from_bits(CANONICAL_NAN_BITS_F64))
+    }
+
+    /// Wraps a float like `From` does, but panics if it is `NAN` and debug assertions are
+    /// enabled. This is a development aid for catching accidental `NAN`s early, not a guarantee:
+    /// in release builds, `NAN` is wrapped without complaint.
+    #[track_caller]
+    pub fn from_checked(value: f64) -> Self {
+        debug_assert!(!value.is_nan(), "F64::from_checked called with NAN");
+        F64(value)
+    }
+
+    /// Returns the inner float if it is not `NAN`, and `None` otherwise.
+    pub fn not_nan(self) -> Option<f64> {
         if self.0.is_nan() {
-            0x7ff8000000000000u64.hash(state); // a particular bit representation for NAN
-        } else if self.0 == 0.0 { // catches both positive and negative zero
-            0u64.hash(state);
+            None
         } else {
-            self.0.to_bits().hash(state);
+            Some(self.0)
         }
     }
-}
 
-impl From<F64> for f64 {
-    fn from(f: F64) -> Self {
-        f.0
+    /// Returns the inner float, panicking with the given message if it is `NAN`.
+    pub fn expect_not_nan(self, msg: &str) -> f64 {
+        self.not_nan().expect(msg)
     }
-}
 
-impl From<f64> for F64 {
-    fn from(f: f64) -> Self {
-        F64(f)
+    /// Returns the inner float.
+    pub const fn as_f64(self) -> f64 {
+        self.0
     }
-}
 
-impl fmt::Display for F64 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(f)
+    /// Returns the inner float rounded to the nearest `f32`. This loses precision, and maps
+    /// values too large for an `f32` to infinity.
+    pub fn as_f32_lossy(self) -> f32 {
+        self.0 as f32
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
+    /// Returns the memory representation of the inner float in big-endian byte order.
+    pub fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
 
-    use super::{F32, F64};
+    /// Returns the memory representation of the inner float in little-endian byte order.
+    pub fn to_le_bytes(self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
 
-    fn calculate_hash<T: Hash>(t: &T) -> u64 {
-        let mut s = DefaultHasher::new();
-        t.hash(&mut s);
-        s.finish()
+    /// Returns the memory representation of the inner float in native byte order.
+    pub fn to_ne_bytes(self) -> [u8; 8] {
+        self.0.to_ne_bytes()
     }
 
-    #[test]
-    fn f32_eq() {
-        assert!(F32(std::f32::NAN) == F32(std::f32::NAN));
-        assert!(F32(std::f32::NAN) != F32(5.0));
-        assert!(F32(5.0) != F32(std::f32::NAN));
-        assert!(F32(0.0) == F32(-0.0));
+    /// Creates a value from its memory representation in big-endian byte order.
+    pub fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        F64(f64::from_be_bytes(bytes))
     }
 
-    #[test]
-    fn f32_cmp() {
-        assert!(F32(std::f32::NAN) == F32(std::f32::NAN));
-        assert!(F32(std::f32::NAN) < F32(5.0));
-        assert!(F32(5.0) > F32(std::f32::NAN));
-        assert!(F32(0.0) == F32(-0.0));
+    /// Creates a value from its memory representation in little-endian byte order.
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        F64(f64::from_le_bytes(bytes))
     }
 
-    #[test]
-    fn f32_hash() {
-        assert!(calculate_hash(&F32(0.0)) == calculate_hash(&F32(-0.0)));
-        assert!(calculate_hash(&F32(std::f32::NAN)) == calculate_hash(&F32(-std::f32::NAN)));
+    /// Creates a value from its memory representation in native byte order.
+    pub fn from_ne_bytes(bytes: [u8; 8]) -> Self {
+        F64(f64::from_ne_bytes(bytes))
     }
 
-    #[test]
-    fn f64_eq() {
-        assert!(F64(std::f64::NAN) == F64(std::f64::NAN));
-        assert!(F64(std::f64::NAN) != F64(5.0));
-        assert!(F64(5.0) != F64(std::f64::NAN));
-        assert!(F64(0.0) == F64(-0.0));
+    /// Returns big-endian bytes whose lexicographic order matches the order of this type: all
+    /// `NAN` encode to the smallest bytes, and both zeros encode to the same bytes.
+    pub fn to_sortable_bytes(self) -> [u8; 8] {
+        self.to_ordered_bits().to_be_bytes()
     }
 
-    #[test]
-    fn f64_cmp() {
-        assert!(F64(std::f64::NAN) == F64(std::f64::NAN));
-        assert!(F64(std::f64::NAN) < F64(5.0));
-        assert!(F64(5.0) > F64(std::f64::NAN));
-        assert!(F64(0.0) == F64(-0.0));
+    /// Decodes bytes produced by `to_sortable_bytes`. This returns `0.0` for either zero and
+    /// `f64::NAN` for any `NAN`.
+    pub fn from_sortable_bytes(bytes: [u8; 8]) -> Self {
+        F64::from_ordered_bits(u64::from_be_bytes(bytes))
     }
 
-    #[test]
-    fn f64_hash() {
-        assert!(calculate_hash(&F64(0.0)) == calculate_hash(&F64(-0.0)));
-        assert!(calculate_hash(&F64(std::f64::NAN)) == calculate_hash(&F64(-std::f64::NAN)));
+    /// Returns an integer whose order matches the order of this type: all `NAN`s map to `0`,
+    /// and both zeros map to `0x8000_0000_0000_0000`. Otherwise, this flips the sign bit of non-negative
+    /// values and all bits of negative values.
+    pub const fn to_ordered_bits(self) -> u64 {
+        ordered_key_f64(self.0.to_bits())
+    }
+
+    /// Inverts `to_ordered_bits`, returning `0.0` for either zero and `f64::NAN` for any `NAN`.
+    /// The integers that `to_ordered_bits` never returns decode to a `NAN` (with some sign and
+    /// payload), except for `!0x8000_0000_0000_0000`, which decodes to `-0.0`.
+    pub fn from_ordered_bits(key: u64) -> Self {
+        if key & 0x8000_0000_0000_0000 != 0 {
+            F64(f64::from_bits(key & !0x8000_0000_0000_0000))
+        } else if key == 0 {
+            F64(f64::NAN)
+        } else {
+            F64(f64::from_bits(!key))
+        }
+    }
+
+    /// Maps `NAN` to `0.0`, positive infinity to `f64::MAX` and negative infinity to
+    /// `f64::MIN`, leaving finite values untouched.
+    pub fn clamp_to_finite(self) -> Self {
+        self.clamp_to_finite_or(F64(0.0))
+    }
+
+    /// Maps `NAN` to `nan_default`, positive infinity to `f64::MAX` and negative infinity to
+    /// `f64::MIN`, leaving finite values untouched.
+    pub fn clamp_to_finite_or(self, nan_default: Self) -> Self {
+        if self.0.is_nan() {
+            nan_default
+        } else if self.0 == f64::INFINITY {
+            F64(f64::MAX)
+        } else if self.0 == f64::NEG_INFINITY {
+            F64(f64::MIN)
+        } else {
+            self
+        }
+    }
+
+    /// Adds `other`, clamping a result that overflowed to infinity to `f64::MAX` or `f64::MIN`.
+    /// Infinite operands still yield infinite results, and `NAN` still yields `NAN`.
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.saturate(other, self.0 + other.0)
+    }
+
+    /// Subtracts `other`, clamping a result that overflowed to infinity to `f64::MAX` or
+    /// `f64::MIN`. Infinite operands still yield infinite results, and `NAN` still yields `NAN`.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self.saturate(other, self.0 - other.0)
+    }
+
+    /// Multiplies by `other`, clamping a result that overflowed to infinity to `f64::MAX` or
+    /// `f64::MIN`. Infinite operands still yield infinite results, and `NAN` still yields `NAN`.
+    pub fn saturating_mul(self, other: Self) -> Self {
+        self.saturate(other, self.0 * other.0)
+    }
+
+    fn saturate(self, other: Self, result: f64) -> Self {
+        if result.is_infinite() && self.0.is_finite() && other.0.is_finite() {
+            F64(result).clamp_to_finite()
+        } else {
+            F64(result)
+        }
+    }
+
+    /// Maps subnormal values (of either sign) to `0.0`, leaving all other values untouched.
+    ///
+    /// Since `0.0 == -0.0` already holds for this type, the sign of a flushed value would not
+    /// affect equality, ordering or hashing anyway; it is normalized to positive zero regardless.
+    pub fn flush_subnormals(self) -> Self {
+        if self.0.is_subnormal() {
+            F64(0.0)
+        } else {
+            self
+        }
+    }
+
+    /// Returns `None` for `0.0` and `-0.0` (which are equal), and `Some(self)` otherwise.
+    pub fn nonzero(self) -> Option<Self> {
+        if self.0 == 0.0 {
+            None
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Returns the wrapped value, or `0.0` for `None`. This undoes `nonzero`, except that it
+    /// always yields positive zero.
+    pub fn or_zero(value: Option<Self>) -> Self {
+        value.unwrap_or(F64(0.0))
+    }
+
+    /// Returns how many steps from one representable float to the next it takes to get from
+    /// `self` to `other` (in either direction), or `None` if either is `NAN`. The two zeros count
+    /// as a single float, so the distance between the least positive and the greatest negative
+    /// float is `2`.
+    pub fn ulps_between(self, other: Self) -> Option<u64> {
+        if self.0.is_nan() || other.0.is_nan() {
+            return None;
+        }
+
+        let (a, b) = (self.0.to_bits(), other.0.to_bits());
+        let a_magnitude = a & !0x8000_0000_0000_0000;
+        let b_magnitude = b & !0x8000_0000_0000_0000;
+        if a_magnitude == 0 || b_magnitude == 0 || (a & 0x8000_0000_0000_0000) == (b & 0x8000_0000_0000_0000) {
+            Some(a_magnitude.abs_diff(b_magnitude))
+        } else {
+            Some(a_magnitude + b_magnitude)
+        }
+    }
+
+    /// Feeds `self.0.to_bits()` into the hasher, without the canonicalization `Hash` does. Use
+    /// this for hashing that distinguishes all bit patterns; it is not consistent with `Eq`.
+    pub fn raw_bits_hash<H: Hasher>(self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+
+    /// Returns a hash of the value that does not depend on any `Hasher`, and that is stable
+    /// across crate versions and platforms. Equal values have equal hashes.
+    ///
+    /// This is the 64 bit FNV-1a hash of the little-endian bytes of the bits of the value, where
+    /// all `NAN`s are represented by `CANONICAL_NAN_BITS_F64` and both zeros by `0`.
+    pub fn stable_hash64(self) -> u64 {
+        fnv1a64(&self.canonical_bits().to_le_bytes())
+    }
+
+    /// Returns the key that identifies the value for hashing. This is injective on the
+    /// equivalence classes of `Eq`: two values have the same key if and only if they are equal.
+    /// All `NAN` share the bits of `f64::NAN` as their key, and both zeros share the key `0`;
+    /// every other value has its bit representation as its key.
+    pub fn hash_key(self) -> u64 {
+        self.canonical_bits()
+    }
+
+    /// Compares like `Ord::cmp` does, but can be evaluated in `const` contexts.
+    pub const fn const_cmp(a: Self, b: Self) -> Ordering {
+        let (a, b) = (a.to_ordered_bits(), b.to_ordered_bits());
+        if a < b {
+            Ordering::Less
+        } else if a > b {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Compares like `PartialEq::eq` does, but can be evaluated in `const` contexts.
+    pub const fn const_eq(a: Self, b: Self) -> bool {
+        a.to_ordered_bits() == b.to_ordered_bits()
+    }
+
+    // The bits that get hashed: a particular bit representation for all NAN, and the same
+    // bits for both positive and negative zero.
+    // This is branch-free, selecting the result with masks.
+    fn canonical_bits(self) -> u64 {
+        let bits = self.0.to_bits();
+        let magnitude = bits & !0x8000_0000_0000_0000;
+        let nan = ((magnitude > 0x7ff0_0000_0000_0000) as u64).wrapping_neg();
+        let zero = ((magnitude == 0) as u64).wrapping_neg();
+        (bits & !zero & !nan) | (CANONICAL_NAN_BITS_F64 & nan)
+    }
+
+    /// Returns a value whose `Debug` output tags the special cases, e.g. `F64(-0.0 [neg_zero])`,
+    /// `F64(NaN)`, `F64(inf)` or `F64(1e-310 [subnormal])`, while normal values print as
+    /// `F64(1.5)`. `Debug` on `F64` itself prints just the inner float.
+    pub fn classified(self) -> impl fmt::Debug {
+        ClassifiedF64(self.0)
+    }
+
+    /// Formats the value like `Display`, except that it always prints `-0` for negative zero
+    /// and `NaN` for any `NAN` (regardless of sign and payload), so that the special cases of
+    /// this crate are unambiguous in logs.
+    #[cfg(feature = "alloc")]
+    pub fn to_debug_string(self) -> String {
+        if self.0.is_nan() {
+            "NaN".to_string()
+        } else if self.0 == 0.0 && self.0.is_sign_negative() {
+            "-0".to_string()
+        } else {
+            self.0.to_string()
+        }
+    }
+}
+
+/// Maps the bits of a float to an integer with the same order as `F64`, see
+/// `F64::to_ordered_bits`. This is branch-free, so that comparisons compile to a few integer
+/// instructions.
+const fn ordered_key_f64(bits: u64) -> u64 {
+    let magnitude = bits & !0x8000_0000_0000_0000;
+    let negative = (bits as i64 >> 63) as u64;
+    // Flips all bits of negative values, and only the sign bit of the others.
+    let key = bits ^ (negative | 0x8000_0000_0000_0000);
+    // Negative zero now sits right below positive zero, so moves up to it.
+    let key = key + ((magnitude == 0) & (negative != 0)) as u64;
+    // All `NAN`s map to `0`, which no other value maps to.
+    key & ((magnitude <= 0x7ff0_0000_0000_0000) as u64).wrapping_neg()
+}
+
+/// This works like `PartialEq` on `f64`, except that `NAN == NAN` is true.
+impl PartialEq for F64 {
+    fn eq(&self, other: &Self) -> bool {
+        F64::const_eq(*self, *other)
+    }
+}
+
+impl Eq for F64 {}
+
+/// This works like `PartialOrd` on `f64`, except that `NAN` sorts below all other floats
+/// (and is equal to another NAN). This always returns a `Some`.
+impl PartialOrd for F64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// This works like `PartialOrd` on `f64`, except that `NAN` sorts below all other floats
+/// (and is equal to another NAN).
+impl Ord for F64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        F64::const_cmp(*self, *other)
+    }
+}
+
+/// Hashes exactly like `self.0.to_bits()` does, except that all `NAN`s hash like
+/// `CANONICAL_NAN_BITS_F64` and negative zero hashes like positive zero. This is guaranteed, so
+/// `Hash` is compatible with hashing the bits of a non-`NAN`, nonzero float directly.
+///
+/// Slices are hashed by writing all their canonical bits in bulk, which matches hashing the
+/// elements one by one only for hashers that treat their input as a byte stream (like the
+/// default `SipHash`).
+impl Hash for F64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_bits().hash(state);
+    }
+
+    // Feeds the canonical bits of many values to the hasher at once, which is faster for
+    // hashers that process large inputs in bulk.
+    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H) {
+        let mut buf = [0u8; 256];
+        for chunk in data.chunks(32) {
+            for (f, bytes) in chunk.iter().zip(buf.chunks_exact_mut(8)) {
+                bytes.copy_from_slice(&f.canonical_bits().to_ne_bytes());
+            }
+            state.write(&buf[..chunk.len() * 8]);
+        }
+    }
+}
+
+impl From<F64> for f64 {
+    fn from(f: F64) -> Self {
+        f.0
+    }
+}
+
+impl From<f64> for F64 {
+    fn from(f: f64) -> Self {
+        F64(f)
+    }
+}
+
+struct ClassifiedF64(f64);
+
+impl fmt::Debug for ClassifiedF64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_nan() {
+            write!(f, "F64(NaN)")
+        } else if self.0.is_infinite() {
+            write!(f, "F64({})", self.0)
+        } else if self.0 == 0.0 && self.0.is_sign_negative() {
+            write!(f, "F64(-0.0 [neg_zero])")
+        } else if self.0.is_subnormal() {
+            write!(f, "F64({:?} [subnormal])", self.0)
+        } else {
+            write!(f, "F64({:?})", self.0)
+        }
+    }
+}
+
+/// Formats exactly like the wrapped `f64`, so `F64(1.0)` prints as `1.0` rather than `F64(1.0)`.
+impl fmt::Debug for F64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for F64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::LowerExp for F64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperExp for F64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperExp::fmt(&self.0, f)
+    }
+}
+
+/// Formats the bits of the representation (not the value), zero-padded to 16 digits. The
+/// alternate flag adds a `0x` prefix.
+impl fmt::LowerHex for F64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_bits(f, self.0.to_bits(), 64, 4, "0x", b"0123456789abcdef")
+    }
+}
+
+/// Formats the bits of the representation (not the value), zero-padded to 16 digits. The
+/// alternate flag adds a `0x` prefix.
+impl fmt::UpperHex for F64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_bits(f, self.0.to_bits(), 64, 4, "0x", b"0123456789ABCDEF")
+    }
+}
+
+/// Formats the bits of the representation (not the value), zero-padded to 64 digits. The
+/// alternate flag adds a `0b` prefix.
+impl fmt::Binary for F64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_bits(f, self.0.to_bits(), 64, 1, "0b", b"01")
+    }
+}
+
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+// Writes the lowest `width` bits of `bits`, `digit_width` bits per digit.
+fn fmt_bits(
+    f: &mut fmt::Formatter,
+    bits: u64,
+    width: u32,
+    digit_width: u32,
+    prefix: &str,
+    digits: &[u8],
+) -> fmt::Result {
+    let mut buf = [0u8; 64];
+    let len = (width / digit_width) as usize;
+    for (i, digit) in buf[..len].iter_mut().enumerate() {
+        let shift = width - digit_width * (i as u32 + 1);
+        *digit = digits[((bits >> shift) & ((1 << digit_width) - 1)) as usize];
+    }
+    // The buffer only contains ASCII digits.
+    f.pad_integral(true, prefix, core::str::from_utf8(&buf[..len]).unwrap())
+}
+
+// Arithmetic works exactly like on the inner floats.
+macro_rules! impl_ops {
+    ($t:ident, $($op:ident, $method:ident, $op_assign:ident, $method_assign:ident;)*) => {
+        $(
+            impl $op for $t {
+                type Output = Self;
+
+                fn $method(self, other: Self) -> Self {
+                    $t(self.0.$method(other.0))
+                }
+            }
+
+            impl $op_assign for $t {
+                fn $method_assign(&mut self, other: Self) {
+                    self.0.$method_assign(other.0)
+                }
+            }
+        )*
+
+        impl Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $t(-self.0)
+            }
+        }
+    };
+}
+
+impl_ops!(F32,
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign;
+);
+
+impl_ops!(F64,
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign;
+);
+
+/// Compares exactly, after losslessly widening the `F32` to `f64`. Note that this means that
+/// `F32(0.1) != F64(0.1)`, since the `f32` closest to `0.1` is not the `f64` closest to `0.1`.
+impl PartialEq<F64> for F32 {
+    fn eq(&self, other: &F64) -> bool {
+        F64(self.0.into()) == *other
+    }
+}
+
+/// Compares exactly, after losslessly widening the `F32` to `f64`. Note that this means that
+/// `F64(0.1) != F32(0.1)`, since the `f32` closest to `0.1` is not the `f64` closest to `0.1`.
+impl PartialEq<F32> for F64 {
+    fn eq(&self, other: &F32) -> bool {
+        *self == F64(other.0.into())
+    }
+}
+
+/// Compares exactly, after losslessly widening the `F32` to `f64`, with the same semantics as
+/// `Ord` on `F64`. This always returns a `Some`.
+impl PartialOrd<F64> for F32 {
+    fn partial_cmp(&self, other: &F64) -> Option<Ordering> {
+        Some(F64(self.0.into()).cmp(other))
+    }
+}
+
+/// Compares exactly, after losslessly widening the `F32` to `f64`, with the same semantics as
+/// `Ord` on `F64`. This always returns a `Some`.
+impl PartialOrd<F32> for F64 {
+    fn partial_cmp(&self, other: &F32) -> Option<Ordering> {
+        Some(self.cmp(&F64(other.0.into())))
+    }
+}
+
+/// Compares with the semantics of `F32`, *not* of `f32`: in particular,
+/// `F32(f32::NAN) == f32::NAN` is true. Note that rustc's `invalid_nan_comparisons` lint fires
+/// on comparisons with the `NAN` constant even here; comparing with a binding avoids it.
+impl PartialEq<f32> for F32 {
+    fn eq(&self, other: &f32) -> bool {
+        *self == F32(*other)
+    }
+}
+
+/// Compares with the semantics of `F32`, *not* of `f32`: in particular,
+/// `f32::NAN == F32(f32::NAN)` is true.
+impl PartialEq<F32> for f32 {
+    fn eq(&self, other: &F32) -> bool {
+        F32(*self) == *other
+    }
+}
+
+/// Compares with the semantics of `F32`, *not* of `f32`: in particular, `NAN` is less than
+/// every other value, so `F32(f32::NAN) < 1.0` is true. This always returns a `Some`.
+impl PartialOrd<f32> for F32 {
+    fn partial_cmp(&self, other: &f32) -> Option<Ordering> {
+        Some(self.cmp(&F32(*other)))
+    }
+}
+
+/// Compares with the semantics of `F32`, *not* of `f32`: in particular, `NAN` is less than
+/// every other value, so `1.0 > F32(f32::NAN)` is true. This always returns a `Some`.
+impl PartialOrd<F32> for f32 {
+    fn partial_cmp(&self, other: &F32) -> Option<Ordering> {
+        Some(F32(*self).cmp(other))
+    }
+}
+
+/// Compares with the semantics of `F64`, *not* of `f64`: in particular,
+/// `F64(f64::NAN) == f64::NAN` is true. Note that rustc's `invalid_nan_comparisons` lint fires
+/// on comparisons with the `NAN` constant even here; comparing with a binding avoids it.
+impl PartialEq<f64> for F64 {
+    fn eq(&self, other: &f64) -> bool {
+        *self == F64(*other)
+    }
+}
+
+/// Compares with the semantics of `F64`, *not* of `f64`: in particular,
+/// `f64::NAN == F64(f64::NAN)` is true.
+impl PartialEq<F64> for f64 {
+    fn eq(&self, other: &F64) -> bool {
+        F64(*self) == *other
+    }
+}
+
+/// Compares with the semantics of `F64`, *not* of `f64`: in particular, `NAN` is less than
+/// every other value, so `F64(f64::NAN) < 1.0` is true. This always returns a `Some`.
+impl PartialOrd<f64> for F64 {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        Some(self.cmp(&F64(*other)))
+    }
+}
+
+/// Compares with the semantics of `F64`, *not* of `f64`: in particular, `NAN` is less than
+/// every other value, so `1.0 > F64(f64::NAN)` is true. This always returns a `Some`.
+impl PartialOrd<F64> for f64 {
+    fn partial_cmp(&self, other: &F64) -> Option<Ordering> {
+        Some(F64(*self).cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
+
+    use super::{F32, F64};
+    #[cfg(feature = "nightly-float")]
+    use super::nightly::{F128, F16};
+
+    fn calculate_hash<T: Hash>(t: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+
+    /// An endless stream of pseudo-random bits from a xorshift generator, so that the tests of
+    /// all modules have a deterministic source of randomness.
+    pub(crate) fn random_bits_u64(seed: u64) -> impl Iterator<Item = u64> {
+        let mut state = seed;
+        core::iter::repeat_with(move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        })
+    }
+
+    /// Like `random_bits_u64`, truncated to the low 32 bits.
+    pub(crate) fn random_bits_u32(seed: u64) -> impl Iterator<Item = u32> {
+        random_bits_u64(seed).map(|bits| bits as u32)
+    }
+
+    // The straightforward implementation of the order, to test the bit-based one against.
+    fn reference_cmp_f32(a: f32, b: f32) -> Ordering {
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => a.partial_cmp(&b).unwrap(),
+        }
+    }
+
+    fn reference_cmp_f64(a: f64, b: f64) -> Ordering {
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => a.partial_cmp(&b).unwrap(),
+        }
+    }
+
+    const SPECIAL_F32_BITS: [u32; 16] = [
+        0, 1, 0x007f_ffff, 0x0080_0000, 0x3f80_0000, 0x7f7f_ffff, 0x7f80_0000, 0x7f80_0001,
+        0x7fc0_0000, 0x7fff_ffff, 0x8000_0000, 0x8000_0001, 0x8080_0000, 0xff80_0000, 0xff80_0001,
+        0xffff_ffff,
+    ];
+
+    #[test]
+    fn f32_cmp_differential() {
+        for &a in &SPECIAL_F32_BITS {
+            for &b in &SPECIAL_F32_BITS {
+                let (a, b) = (f32::from_bits(a), f32::from_bits(b));
+                assert_eq!(F32(a).cmp(&F32(b)), reference_cmp_f32(a, b));
+                assert_eq!(F32(a) == F32(b), reference_cmp_f32(a, b) == Ordering::Equal);
+            }
+        }
+
+        for state in random_bits_u64(0x2545_f491_4f6c_dd1d).take(1_000_000) {
+            let (a, b) = (f32::from_bits(state as u32), f32::from_bits((state >> 32) as u32));
+            assert_eq!(F32(a).cmp(&F32(b)), reference_cmp_f32(a, b));
+            assert_eq!(F32(a) == F32(b), reference_cmp_f32(a, b) == Ordering::Equal);
+        }
+    }
+
+    // Compares every `f32` against each special value. Takes a while, so run it explicitly (in
+    // release mode) with `cargo test --release -- --ignored`.
+    #[test]
+    #[ignore]
+    fn f32_cmp_exhaustive() {
+        for a in 0..=u32::MAX {
+            let a = f32::from_bits(a);
+            for &b in &SPECIAL_F32_BITS {
+                let b = f32::from_bits(b);
+                assert_eq!(F32(a).cmp(&F32(b)), reference_cmp_f32(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn f64_cmp_differential() {
+        let special: Vec<f64> = SPECIAL_F32_BITS
+            .iter()
+            .map(|&bits| f64::from(f32::from_bits(bits)))
+            .chain([f64::from_bits(1), -f64::from_bits(1), f64::MAX, f64::MIN])
+            .collect();
+        for &a in &special {
+            for &b in &special {
+                assert_eq!(F64(a).cmp(&F64(b)), reference_cmp_f64(a, b));
+                assert_eq!(F64(a) == F64(b), reference_cmp_f64(a, b) == Ordering::Equal);
+            }
+        }
+
+        let mut previous = 0.0;
+        for state in random_bits_u64(0x9e37_79b9_7f4a_7c15).take(1_000_000) {
+            let a = f64::from_bits(state);
+            assert_eq!(F64(a).cmp(&F64(previous)), reference_cmp_f64(a, previous));
+            assert_eq!(F64(a) == F64(previous), reference_cmp_f64(a, previous) == Ordering::Equal);
+            previous = a;
+        }
+    }
+
+    #[test]
+    fn f32_canonical_bits_differential() {
+        let reference = |f: f32| {
+            if f.is_nan() {
+                super::CANONICAL_NAN_BITS_F32
+            } else if f == 0.0 {
+                0
+            } else {
+                f.to_bits()
+            }
+        };
+        let mut values: Vec<f32> = random_bits_u32(0x2545_f491_4f6c_dd1d)
+            .take(10_000)
+            .map(f32::from_bits)
+            .collect();
+        values.extend_from_slice(&[
+            0.0,
+            -0.0,
+            f32::NAN,
+            -f32::NAN,
+            f32::from_bits(f32::INFINITY.to_bits() + 1),
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::from_bits(1),
+            -f32::from_bits(1),
+            f32::MAX,
+        ]);
+        for &f in &values {
+            assert_eq!(F32(f).canonical_bits(), reference(f));
+            assert_eq!(calculate_hash(&F32(f)), calculate_hash(&reference(f)));
+        }
+
+        let wrapped: Vec<F32> = values.iter().map(|&f| F32(f)).collect();
+        let mut s = DefaultHasher::new();
+        wrapped.len().hash(&mut s);
+        for &f in &values {
+            reference(f).hash(&mut s);
+        }
+        assert_eq!(calculate_hash(&wrapped), s.finish());
+        assert_eq!(calculate_hash(&[F32(0.0), F32(f32::NAN)]), calculate_hash(&[F32(-0.0), F32(-f32::NAN)]));
+    }
+
+    #[test]
+    fn f64_canonical_bits_differential() {
+        let reference = |f: f64| {
+            if f.is_nan() {
+                super::CANONICAL_NAN_BITS_F64
+            } else if f == 0.0 {
+                0
+            } else {
+                f.to_bits()
+            }
+        };
+        let mut values: Vec<f64> = random_bits_u64(0x2545_f491_4f6c_dd1d)
+            .take(10_000)
+            .map(f64::from_bits)
+            .collect();
+        values.extend_from_slice(&[
+            0.0,
+            -0.0,
+            f64::NAN,
+            -f64::NAN,
+            f64::from_bits(f64::INFINITY.to_bits() + 1),
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::from_bits(1),
+            -f64::from_bits(1),
+            f64::MAX,
+        ]);
+        for &f in &values {
+            assert_eq!(F64(f).canonical_bits(), reference(f));
+            assert_eq!(calculate_hash(&F64(f)), calculate_hash(&reference(f)));
+        }
+
+        let wrapped: Vec<F64> = values.iter().map(|&f| F64(f)).collect();
+        let mut s = DefaultHasher::new();
+        wrapped.len().hash(&mut s);
+        for &f in &values {
+            reference(f).hash(&mut s);
+        }
+        assert_eq!(calculate_hash(&wrapped), s.finish());
+        assert_eq!(calculate_hash(&[F64(0.0), F64(f64::NAN)]), calculate_hash(&[F64(-0.0), F64(-f64::NAN)]));
+    }
+
+    // The semantics shared by all wrappers, tested for each of them so they can't drift apart.
+    macro_rules! semantics_tests {
+        ($eq:ident, $cmp:ident, $hash:ident, $t:ident, $float:ident) => {
+            #[test]
+            fn $eq() {
+                assert!($t($float::NAN) == $t($float::NAN));
+                assert!($t($float::NAN) != $t(5.0));
+                assert!($t(5.0) != $t($float::NAN));
+                assert!($t(0.0) == $t(-0.0));
+            }
+
+            #[test]
+            fn $cmp() {
+                assert!($t($float::NAN) == $t($float::NAN));
+                assert!($t($float::NAN) < $t(5.0));
+                assert!($t(5.0) > $t($float::NAN));
+                assert!($t(0.0) == $t(-0.0));
+            }
+
+            #[test]
+            fn $hash() {
+                assert!(calculate_hash(&$t(0.0)) == calculate_hash(&$t(-0.0)));
+                assert!(calculate_hash(&$t($float::NAN)) == calculate_hash(&$t(-$float::NAN)));
+            }
+        };
+    }
+
+    semantics_tests!(f32_eq, f32_cmp, f32_hash, F32, f32);
+    semantics_tests!(f64_eq, f64_cmp, f64_hash, F64, f64);
+    #[cfg(feature = "half")]
+    fn half_f16(f: f32) -> crate::F16 {
+        crate::F16(half::f16::from_f32(f))
+    }
+    #[cfg(feature = "half")]
+    semantics_tests!(half_f16_eq, half_f16_cmp, half_f16_hash, half_f16, f32);
+    #[cfg(feature = "nightly-float")]
+    semantics_tests!(f16_eq, f16_cmp, f16_hash, F16, f16);
+    #[cfg(feature = "nightly-float")]
+    semantics_tests!(f128_eq, f128_cmp, f128_hash, F128, f128);
+
+    #[cfg(feature = "nightly-float")]
+    #[test]
+    fn wider_generic() {
+        use super::EqFloat;
+
+        let values = [f16::NAN, -f16::NAN, f16::NEG_INFINITY, -1.5, -0.0, 0.0, 2.0, f16::INFINITY];
+        for &a in &values {
+            for &b in &values {
+                assert_eq!(EqFloat(a) == EqFloat(b), F16(a) == F16(b));
+                assert_eq!(EqFloat(a).cmp(&EqFloat(b)), F16(a).cmp(&F16(b)));
+                let (a, b) = (a as f128, b as f128);
+                assert_eq!(EqFloat(a) == EqFloat(b), F128(a) == F128(b));
+                assert_eq!(EqFloat(a).cmp(&EqFloat(b)), F128(a).cmp(&F128(b)));
+            }
+            assert_eq!(calculate_hash(&EqFloat(a)), calculate_hash(&F16(a)));
+            assert_eq!(calculate_hash(&EqFloat(a as f128)), calculate_hash(&F128(a as f128)));
+        }
+        assert!(F16(f16::NAN) < F16(f16::NEG_INFINITY));
+        assert_eq!(calculate_hash(&F16(-0.0)), calculate_hash(&0u16));
+        assert_eq!(
+            calculate_hash(&F128(-f128::NAN)),
+            calculate_hash(&super::nightly::CANONICAL_NAN_BITS_F128)
+        );
+    }
+
+    #[cfg(feature = "nightly-float")]
+    #[test]
+    fn wider_canonical_nan() {
+        use super::nightly::{CANONICAL_NAN_BITS_F128, CANONICAL_NAN_BITS_F16};
+
+        assert_eq!(F16::canonical_nan().0.to_bits(), CANONICAL_NAN_BITS_F16);
+        assert_eq!(F128::canonical_nan().0.to_bits(), CANONICAL_NAN_BITS_F128);
+        assert!(F16::canonical_nan().0.is_nan());
+        assert!(F128::canonical_nan().0.is_nan());
+        assert_ne!(F16(-f16::NAN).0.to_bits(), CANONICAL_NAN_BITS_F16);
+        assert_ne!(F128(-f128::NAN).0.to_bits(), CANONICAL_NAN_BITS_F128);
+        assert_eq!(calculate_hash(&F16::canonical_nan()), calculate_hash(&CANONICAL_NAN_BITS_F16));
+        assert_eq!(calculate_hash(&F128::canonical_nan()), calculate_hash(&CANONICAL_NAN_BITS_F128));
+        assert_eq!(calculate_hash(&F16(-f16::NAN)), calculate_hash(&CANONICAL_NAN_BITS_F16));
+        assert_eq!(calculate_hash(&F16(f16::from_bits(0xfe01))), calculate_hash(&CANONICAL_NAN_BITS_F16));
+        assert_eq!(calculate_hash(&F128(-f128::NAN)), calculate_hash(&CANONICAL_NAN_BITS_F128));
+    }
+
+    #[test]
+    fn f32_constructors() {
+        assert!(F32::nan().0.is_nan());
+        assert_eq!(F32::nan(), F32(f32::NAN));
+        assert_eq!(F32::infinity().0, f32::INFINITY);
+        assert_eq!(F32::neg_infinity().0, f32::NEG_INFINITY);
+        assert!(F32::nan() < F32::neg_infinity());
+        assert_eq!(F32::default().0.to_bits(), 0);
+    }
+
+    #[test]
+    fn f32_not_nan() {
+        assert_eq!(F32(5.0).not_nan(), Some(5.0));
+        assert_eq!(F32(f32::NAN).not_nan(), None);
+        assert_eq!(F32(-0.0).expect_not_nan("zero is not NAN"), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "score must not be NAN")]
+    fn f32_expect_not_nan_panics() {
+        F32(f32::NAN).expect_not_nan("score must not be NAN");
+    }
+
+    #[test]
+    fn f32_from_checked() {
+        assert_eq!(F32::from_checked(1.5), F32(1.5));
+        assert_eq!(F32::from_checked(f32::INFINITY), F32(f32::INFINITY));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "called with NAN")]
+    fn f32_from_checked_nan() {
+        F32::from_checked(f32::NAN);
+    }
+
+    #[test]
+    fn f32_canonical_nan() {
+        assert_eq!(F32::canonical_nan().0.to_bits(), super::CANONICAL_NAN_BITS_F32);
+        assert_eq!(calculate_hash(&F32(f32::NAN)), calculate_hash(&super::CANONICAL_NAN_BITS_F32));
+        assert_eq!(calculate_hash(&F32(-f32::NAN)), calculate_hash(&super::CANONICAL_NAN_BITS_F32));
+    }
+
+    #[test]
+    fn f32_ordered_bits() {
+        let mut random = random_bits_u32(0x9e37_79b9_7f4a_7c15).map(|bits| F32(f32::from_bits(bits)));
+        for _ in 0..10_000 {
+            let (a, b) = (random.next().unwrap(), random.next().unwrap());
+            assert_eq!(a.cmp(&b), a.to_ordered_bits().cmp(&b.to_ordered_bits()));
+            assert_eq!(F32::from_ordered_bits(a.to_ordered_bits()), a);
+        }
+
+        assert_eq!(F32(-f32::NAN).to_ordered_bits(), 0);
+        assert_eq!(F32(-0.0).to_ordered_bits(), F32(0.0).to_ordered_bits());
+        assert!(F32(f32::NEG_INFINITY).to_ordered_bits() > 0);
+        assert!(F32::from_ordered_bits(0).0.is_nan());
+        assert!(F32::from_ordered_bits(1).0.is_nan());
+        assert!(F32::from_ordered_bits(u32::MAX).0.is_nan());
+        assert_eq!(F32::from_ordered_bits(!0x8000_0000).0.to_bits(), 0x8000_0000);
+    }
+
+    #[test]
+    fn f32_accessors() {
+        assert_eq!(F32(1.5).as_f32(), 1.5);
+        assert_eq!(F32(1.5).as_f64(), 1.5);
+        assert_ne!(F32(0.1).as_f64(), 0.1);
+        assert!(F64(F32(0.1).as_f64()) != F64(0.1));
+        assert!(F32(f32::NAN).as_f64().is_nan());
+    }
+
+    #[test]
+    fn f32_const_cmp() {
+        const LESS: Ordering = F32::const_cmp(F32(f32::NAN), F32(f32::NEG_INFINITY));
+        const _: () = assert!(F32::const_eq(F32(0.0), F32(-0.0)));
+        const _: () = assert!(F32::const_eq(F32(f32::NAN), F32(-f32::NAN)));
+        const GREATER: Ordering = F32::const_cmp(F32(1.0), F32(-2.0));
+        assert_eq!(LESS, Ordering::Less);
+        assert_eq!(GREATER, Ordering::Greater);
+        assert!(!F32::const_eq(F32(1.0), F32(f32::NAN)));
+        assert_eq!(F32::const_cmp(F32(-1.0), F32(-0.5)), Ordering::Less);
+        assert_eq!(F32::const_cmp(F32(f32::from_bits(1)), F32(-0.0)), Ordering::Greater);
+    }
+
+    #[test]
+    fn f32_bytes() {
+        assert_eq!(F32(1.0).to_be_bytes(), [0x3f, 0x80, 0, 0]);
+        assert_eq!(F32::from_be_bytes([0x3f, 0x80, 0, 0]), F32(1.0));
+        assert_eq!(F32::from_ne_bytes(F32(-2.5).to_ne_bytes()), F32(-2.5));
+
+        let nan = F32::from_le_bytes(F32(-f32::NAN).to_le_bytes());
+        assert_eq!(nan, F32(f32::NAN));
+        assert_eq!(nan.0.to_bits(), (-f32::NAN).to_bits());
+    }
+
+    #[test]
+    fn f32_sortable_bytes() {
+        let mut values = [
+            F32(2.5),
+            F32(-f32::NAN),
+            F32(f32::INFINITY),
+            F32(-0.0),
+            F32(f32::MIN),
+            F32(f32::from_bits(1)),
+            F32(f32::NEG_INFINITY),
+            F32(0.0),
+            F32(-f32::from_bits(1)),
+            F32(f32::NAN),
+            F32(-2.5),
+            F32(f32::MAX),
+        ];
+        let mut encoded: Vec<_> = values.iter().map(|f| f.to_sortable_bytes()).collect();
+        values.sort();
+        encoded.sort();
+        let decoded: Vec<_> = encoded.into_iter().map(F32::from_sortable_bytes).collect();
+        assert_eq!(decoded, values);
+
+        assert_eq!(F32(-0.0).to_sortable_bytes(), F32(0.0).to_sortable_bytes());
+        assert_eq!(F32(f32::NAN).to_sortable_bytes(), F32(-f32::NAN).to_sortable_bytes());
+        assert!(F32(f32::NAN).to_sortable_bytes() < F32(f32::NEG_INFINITY).to_sortable_bytes());
+        assert_eq!(F32::from_sortable_bytes(F32(-1.5).to_sortable_bytes()).0, -1.5);
+    }
+
+    #[test]
+    fn f32_clamp_to_finite() {
+        assert_eq!(F32(f32::NAN).clamp_to_finite(), F32(0.0));
+        assert_eq!(F32(f32::INFINITY).clamp_to_finite(), F32(f32::MAX));
+        assert_eq!(F32(f32::NEG_INFINITY).clamp_to_finite(), F32(f32::MIN));
+        assert_eq!(F32(-2.5).clamp_to_finite(), F32(-2.5));
+        assert_eq!(F32(f32::NAN).clamp_to_finite_or(F32(1.0)), F32(1.0));
+        assert_eq!(F32(f32::INFINITY).clamp_to_finite_or(F32(1.0)), F32(f32::MAX));
+        assert_eq!(F32(3.0).clamp_to_finite_or(F32(1.0)), F32(3.0));
+    }
+
+    #[test]
+    fn f32_saturating() {
+        assert_eq!(F32(f32::MAX).saturating_add(F32(f32::MAX)), F32(f32::MAX));
+        assert_eq!(F32(f32::MIN).saturating_sub(F32(f32::MAX)), F32(f32::MIN));
+        assert_eq!(F32(f32::MAX).saturating_mul(F32(-2.0)), F32(f32::MIN));
+        assert_eq!(F32(1.5).saturating_add(F32(2.0)), F32(3.5));
+        assert_eq!(F32(f32::INFINITY).saturating_add(F32(1.0)), F32(f32::INFINITY));
+        assert_eq!(F32(f32::NAN).saturating_mul(F32(2.0)), F32(f32::NAN));
+        assert_eq!(F32(f32::INFINITY).saturating_sub(F32(f32::INFINITY)), F32(f32::NAN));
+    }
+
+    #[test]
+    fn f32_raw_bits_hash() {
+        let raw_hash = |f: F32| {
+            let mut s = DefaultHasher::new();
+            f.raw_bits_hash(&mut s);
+            s.finish()
+        };
+        for &x in &[1.5, -2.0, f32::MIN_POSITIVE, f32::from_bits(1), f32::INFINITY, f32::MAX] {
+            assert_eq!(calculate_hash(&F32(x)), calculate_hash(&x.to_bits()));
+            assert_eq!(raw_hash(F32(x)), calculate_hash(&F32(x)));
+        }
+        assert_eq!(raw_hash(F32(-0.0)), calculate_hash(&(-0.0f32).to_bits()));
+        assert_ne!(raw_hash(F32(-0.0)), raw_hash(F32(0.0)));
+        assert_ne!(raw_hash(F32(f32::NAN)), raw_hash(F32(-f32::NAN)));
+        assert_eq!(calculate_hash(&F32(f32::NAN)), calculate_hash(&F32(-f32::NAN)));
+    }
+
+    #[test]
+    fn f32_ulps_between() {
+        let up = |x: f32| f32::from_bits(x.to_bits() + 1);
+        let tiny = f32::from_bits(1);
+        assert_eq!(F32(1.0).ulps_between(F32(up(1.0))), Some(1));
+        assert_eq!(F32(up(1.0)).ulps_between(F32(1.0)), Some(1));
+        assert_eq!(F32(-1.0).ulps_between(F32(-up(1.0))), Some(1));
+        assert_eq!(F32(1.5).ulps_between(F32(1.5)), Some(0));
+        assert_eq!(F32(0.0).ulps_between(F32(-0.0)), Some(0));
+        assert_eq!(F32(-0.0).ulps_between(F32(tiny)), Some(1));
+        assert_eq!(F32(-tiny).ulps_between(F32(0.0)), Some(1));
+        assert_eq!(F32(-tiny).ulps_between(F32(tiny)), Some(2));
+        assert_eq!(F32(-up(tiny)).ulps_between(F32(tiny)), Some(3));
+        assert_eq!(
+            F32(f32::NEG_INFINITY).ulps_between(F32(f32::INFINITY)),
+            Some(2 * u64::from(f32::INFINITY.to_bits()))
+        );
+        assert_eq!(F32(f32::NAN).ulps_between(F32(1.0)), None);
+        assert_eq!(F32(1.0).ulps_between(F32(-f32::NAN)), None);
+    }
+
+    #[test]
+    fn f32_nonzero() {
+        assert_eq!(F32(0.0).nonzero(), None);
+        assert_eq!(F32(-0.0).nonzero(), None);
+        assert_eq!(F32(-2.5).nonzero(), Some(F32(-2.5)));
+        assert_eq!(F32(f32::NAN).nonzero(), Some(F32(f32::NAN)));
+        assert_eq!(F32::or_zero(None).0.to_bits(), 0);
+        assert_eq!(F32::or_zero(F32(-0.0).nonzero()), F32(0.0));
+        assert_eq!(F32::or_zero(Some(F32(1.5))), F32(1.5));
+    }
+
+    #[test]
+    fn f32_flush_subnormals() {
+        let smallest = F32(f32::from_bits(1));
+        assert!(smallest.0.is_subnormal());
+        assert_eq!(smallest.flush_subnormals().0.to_bits(), 0);
+        assert_eq!((-smallest).flush_subnormals().0.to_bits(), 0);
+        assert_eq!(F32(f32::MIN_POSITIVE).flush_subnormals(), F32(f32::MIN_POSITIVE));
+        assert_eq!(F32(-1.5).flush_subnormals(), F32(-1.5));
+        assert_eq!(F32(-0.0).flush_subnormals().0.to_bits(), (-0.0f32).to_bits());
+        assert_eq!(F32(f32::NAN).flush_subnormals(), F32(f32::NAN));
+    }
+
+    #[test]
+    fn f32_debug() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Point {
+            x: F32,
+        }
+
+        assert_eq!(format!("{:?}", F32(1.0)), "1.0");
+        assert_eq!(format!("{:?}", F32(f32::NAN)), "NaN");
+        assert_eq!(format!("{:?}", vec![F32(1.0), F32(2.5)]), format!("{:?}", vec![1.0f32, 2.5]));
+        assert_eq!(format!("{:?}", Point { x: F32(-0.0) }), "Point { x: -0.0 }");
+        assert_eq!(format!("{:#?}", Point { x: F32(0.5) }), "Point {\n    x: 0.5,\n}");
+    }
+
+    #[test]
+    fn f32_classified() {
+        assert_eq!(format!("{:?}", F32(1.5).classified()), "F32(1.5)");
+        assert_eq!(format!("{:?}", F32(0.0).classified()), "F32(0.0)");
+        assert_eq!(format!("{:?}", F32(-0.0).classified()), "F32(-0.0 [neg_zero])");
+        assert_eq!(format!("{:?}", F32(-f32::NAN).classified()), "F32(NaN)");
+        assert_eq!(format!("{:?}", F32(f32::INFINITY).classified()), "F32(inf)");
+        assert_eq!(format!("{:?}", F32(f32::NEG_INFINITY).classified()), "F32(-inf)");
+        assert_eq!(format!("{:?}", F32(1e-40).classified()), "F32(1e-40 [subnormal])");
+        // Subnormal as an `f32`, but normal as an `f64`.
+        assert_eq!(format!("{:?}", F64(1e-40).classified()), "F64(1e-40)");
+    }
+
+    #[test]
+    fn f32_exp() {
+        for &x in &[0.0, -1.5, 1234.5678, f32::from_bits(1), f32::MIN_POSITIVE, f32::INFINITY, f32::NAN] {
+            assert_eq!(format!("{:e}", F32(x)), format!("{:e}", x));
+            assert_eq!(format!("{:E}", F32(x)), format!("{:E}", x));
+            assert_eq!(format!("{:.3e}", F32(x)), format!("{:.3e}", x));
+            assert_eq!(format!("{:>+12.2E}", F32(x)), format!("{:>+12.2E}", x));
+        }
+    }
+
+    #[test]
+    fn f32_bits_fmt() {
+        assert_eq!(format!("{:x}", F32(1.0)), "3f800000");
+        assert_eq!(format!("{:#X}", F32(-0.0)), "0x80000000");
+        assert_eq!(format!("{:x}", F32(f32::from_bits(0x7fc0_1234))), "7fc01234");
+        assert_eq!(format!("{:X}", F32(f32::from_bits(1))), "00000001");
+        assert_eq!(format!("{:#b}", F32(f32::from_bits(1))), "0b00000000000000000000000000000001");
+        assert_eq!(format!("{:b}", F32(-0.0)), "10000000000000000000000000000000");
+        assert_eq!(format!("{:>12x}", F32(1.0)), "    3f800000");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn f32_to_debug_string() {
+        assert_eq!(F32(0.0).to_debug_string(), "0");
+        assert_eq!(F32(-0.0).to_debug_string(), "-0");
+        assert_eq!(F32(-f32::NAN).to_debug_string(), "NaN");
+        assert_eq!(F32(-1.5).to_debug_string(), "-1.5");
+    }
+
+    #[test]
+    fn f64_constructors() {
+        assert!(F64::nan().0.is_nan());
+        assert_eq!(F64::nan(), F64(f64::NAN));
+        assert_eq!(F64::infinity().0, f64::INFINITY);
+        assert_eq!(F64::neg_infinity().0, f64::NEG_INFINITY);
+        assert!(F64::nan() < F64::neg_infinity());
+        assert_eq!(F64::default().0.to_bits(), 0);
+    }
+
+    #[test]
+    fn f64_not_nan() {
+        assert_eq!(F64(5.0).not_nan(), Some(5.0));
+        assert_eq!(F64(f64::NAN).not_nan(), None);
+        assert_eq!(F64(-0.0).expect_not_nan("zero is not NAN"), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "score must not be NAN")]
+    fn f64_expect_not_nan_panics() {
+        F64(f64::NAN).expect_not_nan("score must not be NAN");
+    }
+
+    #[test]
+    fn f64_from_checked() {
+        assert_eq!(F64::from_checked(1.5), F64(1.5));
+        assert_eq!(F64::from_checked(f64::INFINITY), F64(f64::INFINITY));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "called with NAN")]
+    fn f64_from_checked_nan() {
+        F64::from_checked(f64::NAN);
+    }
+
+    #[test]
+    fn f64_canonical_nan() {
+        assert_eq!(F64::canonical_nan().0.to_bits(), super::CANONICAL_NAN_BITS_F64);
+        assert_eq!(calculate_hash(&F64(f64::NAN)), calculate_hash(&super::CANONICAL_NAN_BITS_F64));
+        assert_eq!(calculate_hash(&F64(-f64::NAN)), calculate_hash(&super::CANONICAL_NAN_BITS_F64));
+    }
+
+    #[test]
+    fn f64_ordered_bits() {
+        let mut random = random_bits_u64(0x9e37_79b9_7f4a_7c15).map(|bits| F64(f64::from_bits(bits)));
+        for _ in 0..10_000 {
+            let (a, b) = (random.next().unwrap(), random.next().unwrap());
+            assert_eq!(a.cmp(&b), a.to_ordered_bits().cmp(&b.to_ordered_bits()));
+            assert_eq!(F64::from_ordered_bits(a.to_ordered_bits()), a);
+        }
+
+        assert_eq!(F64(-f64::NAN).to_ordered_bits(), 0);
+        assert_eq!(F64(-0.0).to_ordered_bits(), F64(0.0).to_ordered_bits());
+        assert!(F64(f64::NEG_INFINITY).to_ordered_bits() > 0);
+        assert!(F64::from_ordered_bits(0).0.is_nan());
+        assert!(F64::from_ordered_bits(1).0.is_nan());
+        assert!(F64::from_ordered_bits(u64::MAX).0.is_nan());
+        assert_eq!(F64::from_ordered_bits(!0x8000_0000_0000_0000).0.to_bits(), 0x8000_0000_0000_0000);
+    }
+
+    #[test]
+    fn f64_accessors() {
+        assert_eq!(F64(1.5).as_f64(), 1.5);
+        assert_eq!(F64(0.1).as_f32_lossy(), 0.1f32);
+        assert_eq!(F64(1.0e300).as_f32_lossy(), f32::INFINITY);
+        assert!(F64(f64::NAN).as_f32_lossy().is_nan());
+    }
+
+    #[test]
+    fn f64_const_cmp() {
+        const LESS: Ordering = F64::const_cmp(F64(f64::NAN), F64(f64::NEG_INFINITY));
+        const _: () = assert!(F64::const_eq(F64(0.0), F64(-0.0)));
+        const _: () = assert!(F64::const_eq(F64(f64::NAN), F64(-f64::NAN)));
+        const GREATER: Ordering = F64::const_cmp(F64(1.0), F64(-2.0));
+        assert_eq!(LESS, Ordering::Less);
+        assert_eq!(GREATER, Ordering::Greater);
+        assert!(!F64::const_eq(F64(1.0), F64(f64::NAN)));
+        assert_eq!(F64::const_cmp(F64(-1.0), F64(-0.5)), Ordering::Less);
+        assert_eq!(F64::const_cmp(F64(f64::from_bits(1)), F64(-0.0)), Ordering::Greater);
+    }
+
+    #[test]
+    fn f64_bytes() {
+        assert_eq!(F64(1.0).to_be_bytes(), [0x3f, 0xf0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(F64::from_be_bytes([0x3f, 0xf0, 0, 0, 0, 0, 0, 0]), F64(1.0));
+        assert_eq!(F64::from_ne_bytes(F64(-2.5).to_ne_bytes()), F64(-2.5));
+
+        let nan = F64::from_le_bytes(F64(-f64::NAN).to_le_bytes());
+        assert_eq!(nan, F64(f64::NAN));
+        assert_eq!(nan.0.to_bits(), (-f64::NAN).to_bits());
+    }
+
+    #[test]
+    fn f64_sortable_bytes() {
+        let mut values = [
+            F64(2.5),
+            F64(-f64::NAN),
+            F64(f64::INFINITY),
+            F64(-0.0),
+            F64(f64::MIN),
+            F64(f64::from_bits(1)),
+            F64(f64::NEG_INFINITY),
+            F64(0.0),
+            F64(-f64::from_bits(1)),
+            F64(f64::NAN),
+            F64(-2.5),
+            F64(f64::MAX),
+        ];
+        let mut encoded: Vec<_> = values.iter().map(|f| f.to_sortable_bytes()).collect();
+        values.sort();
+        encoded.sort();
+        let decoded: Vec<_> = encoded.into_iter().map(F64::from_sortable_bytes).collect();
+        assert_eq!(decoded, values);
+
+        assert_eq!(F64(-0.0).to_sortable_bytes(), F64(0.0).to_sortable_bytes());
+        assert_eq!(F64(f64::NAN).to_sortable_bytes(), F64(-f64::NAN).to_sortable_bytes());
+        assert!(F64(f64::NAN).to_sortable_bytes() < F64(f64::NEG_INFINITY).to_sortable_bytes());
+        assert_eq!(F64::from_sortable_bytes(F64(-1.5).to_sortable_bytes()).0, -1.5);
+    }
+
+    #[test]
+    fn f64_clamp_to_finite() {
+        assert_eq!(F64(f64::NAN).clamp_to_finite(), F64(0.0));
+        assert_eq!(F64(f64::INFINITY).clamp_to_finite(), F64(f64::MAX));
+        assert_eq!(F64(f64::NEG_INFINITY).clamp_to_finite(), F64(f64::MIN));
+        assert_eq!(F64(-2.5).clamp_to_finite(), F64(-2.5));
+        assert_eq!(F64(f64::NAN).clamp_to_finite_or(F64(1.0)), F64(1.0));
+        assert_eq!(F64(f64::INFINITY).clamp_to_finite_or(F64(1.0)), F64(f64::MAX));
+        assert_eq!(F64(3.0).clamp_to_finite_or(F64(1.0)), F64(3.0));
+    }
+
+    #[test]
+    fn f64_saturating() {
+        assert_eq!(F64(f64::MAX).saturating_add(F64(f64::MAX)), F64(f64::MAX));
+        assert_eq!(F64(f64::MIN).saturating_sub(F64(f64::MAX)), F64(f64::MIN));
+        assert_eq!(F64(f64::MAX).saturating_mul(F64(-2.0)), F64(f64::MIN));
+        assert_eq!(F64(1.5).saturating_add(F64(2.0)), F64(3.5));
+        assert_eq!(F64(f64::INFINITY).saturating_add(F64(1.0)), F64(f64::INFINITY));
+        assert_eq!(F64(f64::NAN).saturating_mul(F64(2.0)), F64(f64::NAN));
+        assert_eq!(F64(f64::INFINITY).saturating_sub(F64(f64::INFINITY)), F64(f64::NAN));
+    }
+
+    #[test]
+    fn f64_raw_bits_hash() {
+        let raw_hash = |f: F64| {
+            let mut s = DefaultHasher::new();
+            f.raw_bits_hash(&mut s);
+            s.finish()
+        };
+        for &x in &[1.5, -2.0, f64::MIN_POSITIVE, f64::from_bits(1), f64::INFINITY, f64::MAX] {
+            assert_eq!(calculate_hash(&F64(x)), calculate_hash(&x.to_bits()));
+            assert_eq!(raw_hash(F64(x)), calculate_hash(&F64(x)));
+        }
+        assert_eq!(raw_hash(F64(-0.0)), calculate_hash(&(-0.0f64).to_bits()));
+        assert_ne!(raw_hash(F64(-0.0)), raw_hash(F64(0.0)));
+        assert_ne!(raw_hash(F64(f64::NAN)), raw_hash(F64(-f64::NAN)));
+        assert_eq!(calculate_hash(&F64(f64::NAN)), calculate_hash(&F64(-f64::NAN)));
+    }
+
+    #[test]
+    fn f64_ulps_between() {
+        let up = |x: f64| f64::from_bits(x.to_bits() + 1);
+        let tiny = f64::from_bits(1);
+        assert_eq!(F64(1.0).ulps_between(F64(up(1.0))), Some(1));
+        assert_eq!(F64(up(1.0)).ulps_between(F64(1.0)), Some(1));
+        assert_eq!(F64(-1.0).ulps_between(F64(-up(1.0))), Some(1));
+        assert_eq!(F64(1.5).ulps_between(F64(1.5)), Some(0));
+        assert_eq!(F64(0.0).ulps_between(F64(-0.0)), Some(0));
+        assert_eq!(F64(-0.0).ulps_between(F64(tiny)), Some(1));
+        assert_eq!(F64(-tiny).ulps_between(F64(0.0)), Some(1));
+        assert_eq!(F64(-tiny).ulps_between(F64(tiny)), Some(2));
+        assert_eq!(F64(-up(tiny)).ulps_between(F64(tiny)), Some(3));
+        assert_eq!(
+            F64(f64::NEG_INFINITY).ulps_between(F64(f64::INFINITY)),
+            Some(2 * f64::INFINITY.to_bits())
+        );
+        assert_eq!(F64(f64::NAN).ulps_between(F64(1.0)), None);
+        assert_eq!(F64(1.0).ulps_between(F64(-f64::NAN)), None);
+    }
+
+    #[test]
+    fn f64_nonzero() {
+        assert_eq!(F64(0.0).nonzero(), None);
+        assert_eq!(F64(-0.0).nonzero(), None);
+        assert_eq!(F64(-2.5).nonzero(), Some(F64(-2.5)));
+        assert_eq!(F64(f64::NAN).nonzero(), Some(F64(f64::NAN)));
+        assert_eq!(F64::or_zero(None).0.to_bits(), 0);
+        assert_eq!(F64::or_zero(F64(-0.0).nonzero()), F64(0.0));
+        assert_eq!(F64::or_zero(Some(F64(1.5))), F64(1.5));
+    }
+
+    #[test]
+    fn f64_flush_subnormals() {
+        let smallest = F64(f64::from_bits(1));
+        assert!(smallest.0.is_subnormal());
+        assert_eq!(smallest.flush_subnormals().0.to_bits(), 0);
+        assert_eq!((-smallest).flush_subnormals().0.to_bits(), 0);
+        assert_eq!(F64(f64::MIN_POSITIVE).flush_subnormals(), F64(f64::MIN_POSITIVE));
+        assert_eq!(F64(-1.5).flush_subnormals(), F64(-1.5));
+        assert_eq!(F64(-0.0).flush_subnormals().0.to_bits(), (-0.0f64).to_bits());
+        assert_eq!(F64(f64::NAN).flush_subnormals(), F64(f64::NAN));
+    }
+
+    #[test]
+    fn f64_debug() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Point {
+            x: F64,
+        }
+
+        assert_eq!(format!("{:?}", F64(1.0)), "1.0");
+        assert_eq!(format!("{:?}", F64(f64::NAN)), "NaN");
+        assert_eq!(format!("{:?}", vec![F64(1.0), F64(2.5)]), format!("{:?}", vec![1.0f64, 2.5]));
+        assert_eq!(format!("{:?}", Point { x: F64(-0.0) }), "Point { x: -0.0 }");
+        assert_eq!(format!("{:#?}", Point { x: F64(0.5) }), "Point {\n    x: 0.5,\n}");
+    }
+
+    #[test]
+    fn f64_classified() {
+        assert_eq!(format!("{:?}", F64(1.5).classified()), "F64(1.5)");
+        assert_eq!(format!("{:?}", F64(0.0).classified()), "F64(0.0)");
+        assert_eq!(format!("{:?}", F64(-0.0).classified()), "F64(-0.0 [neg_zero])");
+        assert_eq!(format!("{:?}", F64(-f64::NAN).classified()), "F64(NaN)");
+        assert_eq!(format!("{:?}", F64(f64::INFINITY).classified()), "F64(inf)");
+        assert_eq!(format!("{:?}", F64(f64::NEG_INFINITY).classified()), "F64(-inf)");
+        assert_eq!(format!("{:?}", F64(1e-310).classified()), "F64(1e-310 [subnormal])");
+    }
+
+    #[test]
+    fn f64_exp() {
+        for &x in &[0.0, -1.5, 1234.5678, f64::from_bits(1), f64::MIN_POSITIVE, f64::INFINITY, f64::NAN] {
+            assert_eq!(format!("{:e}", F64(x)), format!("{:e}", x));
+            assert_eq!(format!("{:E}", F64(x)), format!("{:E}", x));
+            assert_eq!(format!("{:.3e}", F64(x)), format!("{:.3e}", x));
+            assert_eq!(format!("{:>+12.2E}", F64(x)), format!("{:>+12.2E}", x));
+        }
+    }
+
+    #[test]
+    fn f64_bits_fmt() {
+        assert_eq!(format!("{:x}", F64(1.0)), "3ff0000000000000");
+        assert_eq!(format!("{:#X}", F64(-0.0)), "0x8000000000000000");
+        assert_eq!(format!("{:x}", F64(f64::from_bits(0x7ff8_0000_0000_1234))), "7ff8000000001234");
+        assert_eq!(format!("{:X}", F64(f64::from_bits(1))), "0000000000000001");
+        assert_eq!(format!("{:#b}", F64(f64::from_bits(1))), format!("0b{}1", "0".repeat(63)));
+        assert_eq!(format!("{:b}", F64(-0.0)), format!("1{}", "0".repeat(63)));
+        assert_eq!(format!("{:>20x}", F64(1.0)), "    3ff0000000000000");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn f64_to_debug_string() {
+        assert_eq!(F64(0.0).to_debug_string(), "0");
+        assert_eq!(F64(-0.0).to_debug_string(), "-0");
+        assert_eq!(F64(-f64::NAN).to_debug_string(), "NaN");
+        assert_eq!(F64(-1.5).to_debug_string(), "-1.5");
+    }
+
+    #[test]
+    fn cross_width_cmp() {
+        assert!(F32(0.5) == F64(0.5));
+        assert!(F64(0.5) == F32(0.5));
+        assert!(F32(0.1) != F64(0.1));
+        assert!(F64(0.1) != F32(0.1));
+        assert!(F32(f32::NAN) == F64(f64::NAN));
+        assert!(F64(-f64::NAN) == F32(f32::NAN));
+        assert!(F32(0.0) == F64(-0.0));
+        assert!(F32(f32::NAN) < F64(f64::NEG_INFINITY));
+        assert!(F64(1.0) > F32(0.5));
+        assert!(F32(f32::INFINITY) == F64(f64::INFINITY));
+        assert!(F32(0.1) > F64(0.1));
+        assert!(F64(0.1) < F32(0.1));
+        assert!(F32(f32::INFINITY) > F64(f64::MAX));
+        assert!(F64(f64::NEG_INFINITY) < F32(f32::MIN));
+        assert!(F64(f64::NAN) < F32(f32::NEG_INFINITY));
+    }
+
+    #[test]
+    fn primitive_cmp() {
+        assert!(F32(0.5) == 0.5);
+        assert!(0.5 == F32(0.5));
+        let nan = f32::NAN;
+        assert!(F32(f32::NAN) == nan);
+        assert!(nan == F32(-f32::NAN));
+        assert!(F32(-0.0) == 0.0);
+        assert!(0.0 == F32(-0.0));
+        assert!(F32(f32::NAN) < 1.0);
+        assert!(1.0 > F32(f32::NAN));
+        assert!(F32(f32::NAN) < f32::NEG_INFINITY);
+        assert!(F32(-0.0) <= 0.0 && F32(-0.0) >= 0.0);
+        assert!(F32(2.0) > 1.0);
+
+        assert!(F64(0.5) == 0.5);
+        assert!(0.5 == F64(0.5));
+        let nan = f64::NAN;
+        assert!(F64(f64::NAN) == nan);
+        assert!(nan == F64(-f64::NAN));
+        assert!(F64(-0.0) == 0.0);
+        assert!(0.0 == F64(-0.0));
+        assert!(F64(f64::NAN) < 1.0);
+        assert!(1.0 > F64(f64::NAN));
+        assert!(f64::NEG_INFINITY > F64(f64::NAN));
+        assert!(0.0 <= F64(-0.0) && 0.0 >= F64(-0.0));
+        assert!(F64(2.0) > 1.0);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(F32(1.5) + F32(2.0), F32(3.5));
+        assert_eq!(F64(1.5) - F64(2.0), F64(-0.5));
+        assert_eq!(F32(1.5) * F32(2.0), F32(3.0));
+        assert_eq!(F64(1.5) / F64(0.0), F64(f64::INFINITY));
+        assert_eq!(F32(7.0) % F32(4.0), F32(3.0));
+        assert_eq!(-F64(0.0), F64(0.0));
+        assert!((-F64(0.0)).0.is_sign_negative());
+
+        let mut f = F64(1.0);
+        f += F64(2.0);
+        f *= F64(f64::NAN);
+        assert_eq!(f, F64(f64::NAN));
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn bevy_reflect() {
+        use bevy_reflect::serde::{ReflectDeserializer, ReflectSerializer};
+        use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+        use serde::de::DeserializeSeed;
+
+        #[derive(Debug, PartialEq, Reflect)]
+        struct Tuning {
+            gain: F32,
+            limit: F32,
+            offset: F64,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Tuning>();
+
+        let tuning = Tuning {
+            gain: F32(0.75),
+            limit: F32(f32::INFINITY),
+            offset: F64(-2.5),
+        };
+        let ron = ron::to_string(&ReflectSerializer::new(&tuning, &registry)).unwrap();
+
+        let mut deserializer = ron::Deserializer::from_str(&ron).unwrap();
+        let reflected = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Tuning::from_reflect(reflected.as_ref()), Some(tuning));
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn zerocopy() {
+        use zerocopy::{AsBytes, FromBytes, Ref};
+
+        let bytes = 1.5f32.to_ne_bytes();
+        assert_eq!(F32::read_from(&bytes[..]), Some(F32(1.5)));
+        assert_eq!(F32(1.5).as_bytes(), &bytes[..]);
+
+        let nan = f32::from_bits(0x7fc0_1234);
+        assert_eq!(F32::read_from(&nan.to_ne_bytes()[..]).unwrap().0.to_bits(), 0x7fc0_1234);
+
+        let words = [2.5f64.to_bits(), f64::NAN.to_bits()];
+        let floats = Ref::<_, [F64]>::new_slice(words.as_bytes())
+            .unwrap()
+            .into_slice();
+        assert_eq!(floats, &[F64(2.5), F64(f64::NAN)][..]);
+    }
+
+    #[test]
+    fn hash_key_injective() {
+        let mut f32_keys: HashMap<u64, F32> = HashMap::new();
+        let specials = [0, 0x8000_0000, 0x7fc0_0000, 0xffc0_0001, 0x7f80_0000, 1];
+        for bits in (0..=u32::MAX).step_by(65_521).chain(specials) {
+            let f = F32(f32::from_bits(bits));
+            let other = *f32_keys.entry(f.hash_key()).or_insert(f);
+            assert_eq!(other, f);
+            assert_eq!(f.hash_key(), u64::from(f.canonical_bits()));
+        }
+        assert_eq!(F32(-0.0).hash_key(), F32(0.0).hash_key());
+        assert_eq!(F32(-f32::NAN).hash_key(), F32(f32::NAN).hash_key());
+
+        let mut f64_keys: HashMap<u64, F64> = HashMap::new();
+        let specials = [0, 1 << 63, f64::NAN.to_bits(), (-f64::NAN).to_bits() | 1, 1];
+        for bits in (0..=u64::MAX).step_by(0x0000_1234_5678_9abd).chain(specials) {
+            let f = F64(f64::from_bits(bits));
+            let other = *f64_keys.entry(f.hash_key()).or_insert(f);
+            assert_eq!(other, f);
+        }
+        assert_eq!(F64(-0.0).hash_key(), F64(0.0).hash_key());
+        assert_eq!(F64(-f64::NAN).hash_key(), F64(f64::NAN).hash_key());
+    }
+
+    #[test]
+    fn stable_hash64() {
+        assert_eq!(F32(0.0).stable_hash64(), 0x4d25767f9dce13f5);
+        assert_eq!(F32(-0.0).stable_hash64(), 0x4d25767f9dce13f5);
+        assert_eq!(F32(1.0).stable_hash64(), 0x4b72477f9c5c2f98);
+        assert_eq!(F32(-1.5).stable_hash64(), 0x4a99477f9ba43ed8);
+        assert_eq!(F32(f32::NAN).stable_hash64(), 0x4a98877f9ba2f898);
+        assert_eq!(F32(-f32::NAN).stable_hash64(), 0x4a98877f9ba2f898);
+        assert_eq!(F32(f32::INFINITY).stable_hash64(), 0x4b72877f9c5c9c58);
+
+        assert_eq!(F64(0.0).stable_hash64(), 0xa8c7f832281a39c5);
+        assert_eq!(F64(-0.0).stable_hash64(), 0xa8c7f832281a39c5);
+        assert_eq!(F64(1.0).stable_hash64(), 0xaab1693229ba1db8);
+        assert_eq!(F64(-1.5).stable_hash64(), 0xaa95693229a1a300);
+        assert_eq!(F64(f64::NAN).stable_hash64(), 0xaa96293229a2e940);
+        assert_eq!(F64(-f64::NAN).stable_hash64(), 0xaa96293229a2e940);
+        assert_eq!(F64(f64::INFINITY).stable_hash64(), 0xaab1293229b9b0f8);
     }
 }
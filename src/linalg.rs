@@ -0,0 +1,67 @@
+//! Small linear algebra helpers over slices of `F64`: `dot` and, with the `std` feature (which
+//! provides the square root), `norm2`. Both propagate `NAN` like the arithmetic operators do.
+
+use crate::F64;
+
+/// Returns the dot product of `a` and `b`, i.e. the sum of their pairwise products. The result is
+/// `NAN` if any input is `NAN`, or if an infinite product meets a zero or another infinity of
+/// opposite sign.
+///
+/// The dot product of two empty slices is `0.0`.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+pub fn dot(a: &[F64], b: &[F64]) -> F64 {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "dot product of slices with different lengths"
+    );
+    a.iter().zip(b).fold(F64(0.0), |sum, (&x, &y)| sum + x * y)
+}
+
+/// Returns the Euclidean norm of `a`, i.e. the square root of the sum of the squares of its
+/// values. The result is `NAN` if any input is `NAN`.
+///
+/// The norm of an empty slice is `0.0`.
+#[cfg(feature = "std")]
+pub fn norm2(a: &[F64]) -> F64 {
+    F64(dot(a, a).0.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dot_product() {
+        assert_eq!(
+            dot(
+                &[F64(1.0), F64(2.0), F64(3.0)],
+                &[F64(4.0), F64(-5.0), F64(6.0)]
+            ),
+            F64(12.0)
+        );
+        assert_eq!(dot(&[], &[]), F64(0.0));
+        assert!(dot(&[F64(1.0), F64(f64::NAN)], &[F64(2.0), F64(3.0)])
+            .0
+            .is_nan());
+        assert!(dot(&[F64(f64::INFINITY)], &[F64(0.0)]).0.is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_length_mismatch() {
+        dot(&[F64(1.0)], &[F64(1.0), F64(2.0)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn norm() {
+        assert_eq!(norm2(&[F64(3.0), F64(-4.0)]), F64(5.0));
+        assert_eq!(norm2(&[]), F64(0.0));
+        assert!(norm2(&[F64(1.0), F64(f64::NAN)]).0.is_nan());
+        assert_eq!(norm2(&[F64(f64::NEG_INFINITY)]), F64(f64::INFINITY));
+    }
+}
@@ -0,0 +1,291 @@
+/// Wraps an `f32` literal in an `F32`, in a way that can be evaluated in constants and statics.
+///
+/// Besides float literals (optionally negated), this accepts `nan` for `F32::canonical_nan()`,
+/// and `inf` and `-inf` for the infinities. Anything else, including integer literals and
+/// expressions like `0.0 / 0.0`, is a compile error, so every value written with `eqf!` has the
+/// bits its literal suggests.
+///
+/// ```
+/// use eq_float::{eqf, F32};
+///
+/// static THRESHOLDS: [F32; 4] = [eqf!(-inf), eqf!(-1.5), eqf!(2.5e-3), eqf!(nan)];
+///
+/// assert_eq!(THRESHOLDS[1], F32(-1.5));
+/// assert_eq!(THRESHOLDS[3].0.to_bits(), eq_float::CANONICAL_NAN_BITS_F32);
+/// match F32(7.0) {
+///     x if x > eqf!(5.0) => {}
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// ```compile_fail
+/// let x = 1.5f32;
+/// let _ = eq_float::eqf!(x);
+/// ```
+///
+/// ```compile_fail
+/// let _ = eq_float::eqf!(0.0 / 0.0);
+/// ```
+///
+/// ```compile_fail,E0308
+/// let _ = eq_float::eqf!(1);
+/// ```
+#[macro_export]
+macro_rules! eqf {
+    (nan) => {
+        $crate::F32::canonical_nan()
+    };
+    (inf) => {
+        $crate::F32::infinity()
+    };
+    (-inf) => {
+        $crate::F32::neg_infinity()
+    };
+    ($lit:literal) => {
+        $crate::F32($lit)
+    };
+}
+
+/// Wraps an `f64` literal in an `F64`, in a way that can be evaluated in constants and statics.
+///
+/// Besides float literals (optionally negated), this accepts `nan` for `F64::canonical_nan()`,
+/// and `inf` and `-inf` for the infinities. Anything else, including integer literals and
+/// expressions like `0.0 / 0.0`, is a compile error, so every value written with `eqd!` has the
+/// bits its literal suggests.
+///
+/// ```
+/// use eq_float::{eqd, F64};
+///
+/// const POWERS: [F64; 3] = [eqd!(1.0), eqd!(1e3), eqd!(1e6)];
+///
+/// assert_eq!(POWERS[2], F64(1_000_000.0));
+/// assert_eq!(eqd!(nan).0.to_bits(), eq_float::CANONICAL_NAN_BITS_F64);
+/// ```
+///
+/// ```compile_fail
+/// let x = 1.5;
+/// let _ = eq_float::eqd!(x);
+/// ```
+///
+/// ```compile_fail
+/// let _ = eq_float::eqd!(0.0 / 0.0);
+/// ```
+///
+/// ```compile_fail,E0308
+/// let _ = eq_float::eqd!("1.5");
+/// ```
+#[macro_export]
+macro_rules! eqd {
+    (nan) => {
+        $crate::F64::canonical_nan()
+    };
+    (inf) => {
+        $crate::F64::infinity()
+    };
+    (-inf) => {
+        $crate::F64::neg_infinity()
+    };
+    ($lit:literal) => {
+        $crate::F64($lit)
+    };
+}
+
+/// Asserts that two floats are equal under the semantics of `F32` and `F64`: all `NAN`s are
+/// equal, and so are the two zeros. The left operand can be a primitive or a wrapper and is
+/// converted with `IntoEqFloat`, the right one is then converted to the same wrapper with `Into`.
+///
+/// On failure, the panic message shows both operands along with their bit patterns. Like
+/// `assert_eq!`, this accepts an optional trailing format string and arguments.
+///
+/// ```
+/// use eq_float::{assert_total_eq, F64};
+///
+/// assert_total_eq!(f64::NAN, -f64::NAN);
+/// assert_total_eq!(F64(0.0), -0.0, "zeros are equal");
+/// ```
+#[macro_export]
+macro_rules! assert_total_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__private::assert_total(true, $left, $right, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::__private::assert_total(
+            true,
+            $left,
+            $right,
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Asserts that two floats are not equal under the semantics of `F32` and `F64`, the counterpart
+/// of `assert_total_eq!`.
+///
+/// If the operands have different bits but are equal only because both are `NAN` or both are
+/// zero, the panic message says so.
+///
+/// ```
+/// use eq_float::assert_total_ne;
+///
+/// assert_total_ne!(1.0f32, 1.0 + f32::EPSILON);
+/// assert_total_ne!(f32::NAN, f32::NEG_INFINITY, "NAN is not equal to {}", "infinity");
+/// ```
+#[macro_export]
+macro_rules! assert_total_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__private::assert_total(false, $left, $right, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::__private::assert_total(
+            false,
+            $left,
+            $right,
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Implementation details of the macros, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use core::fmt;
+
+    use crate::{IntoEqFloat, F32, F64};
+
+    pub trait AssertTotal: Copy + PartialEq + fmt::Debug + fmt::LowerHex {
+        /// Explains why `self` and `other` compare equal although their bits differ, if they do.
+        fn note(self, other: Self) -> &'static str;
+    }
+
+    impl AssertTotal for F32 {
+        fn note(self, other: Self) -> &'static str {
+            if self.0.to_bits() == other.0.to_bits() {
+                ""
+            } else if self.0.is_nan() && other.0.is_nan() {
+                "\n  note: both operands are NAN, with different bits"
+            } else if self.0 == 0.0 && other.0 == 0.0 {
+                "\n  note: the operands differ only in the sign of zero"
+            } else {
+                ""
+            }
+        }
+    }
+
+    impl AssertTotal for F64 {
+        fn note(self, other: Self) -> &'static str {
+            if self.0.to_bits() == other.0.to_bits() {
+                ""
+            } else if self.0.is_nan() && other.0.is_nan() {
+                "\n  note: both operands are NAN, with different bits"
+            } else if self.0 == 0.0 && other.0 == 0.0 {
+                "\n  note: the operands differ only in the sign of zero"
+            } else {
+                ""
+            }
+        }
+    }
+
+    /// Panics unless the operands being equal is `expect_eq`.
+    #[track_caller]
+    pub fn assert_total<L, R>(expect_eq: bool, left: L, right: R, message: Option<fmt::Arguments>)
+    where
+        L: IntoEqFloat,
+        L::Output: AssertTotal,
+        R: Into<L::Output>,
+    {
+        let (left, right) = (left.eq_float(), right.into());
+        if (left == right) == expect_eq {
+            return;
+        }
+
+        let op = if expect_eq { "==" } else { "!=" };
+        let note = left.note(right);
+        match message {
+            Some(message) => panic!(
+                "assertion `left {} right` failed under eq-float semantics: {}\n  left: {:?} ({:#x})\n right: {:?} ({:#x}){}",
+                op, message, left, left, right, right, note
+            ),
+            None => panic!(
+                "assertion `left {} right` failed under eq-float semantics\n  left: {:?} ({:#x})\n right: {:?} ({:#x}){}",
+                op, left, left, right, right, note
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{F32, F64};
+
+    const F32_TABLE: [F32; 5] = [eqf!(nan), eqf!(-inf), eqf!(-0.0), eqf!(1.5), eqf!(inf)];
+    static F64_TABLE: [F64; 5] = [eqd!(nan), eqd!(-inf), eqd!(-0.0), eqd!(2.5e-3), eqd!(inf)];
+
+    #[test]
+    fn f32_literals() {
+        assert_eq!(F32_TABLE[0].0.to_bits(), crate::CANONICAL_NAN_BITS_F32);
+        assert_eq!(F32_TABLE[1], F32(f32::NEG_INFINITY));
+        assert_eq!(F32_TABLE[2].0.to_bits(), 0x8000_0000);
+        assert_eq!(F32_TABLE[3], F32(1.5));
+        assert_eq!(F32_TABLE[4], F32(f32::INFINITY));
+        assert!(F32_TABLE.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn f64_literals() {
+        assert_eq!(F64_TABLE[0].0.to_bits(), crate::CANONICAL_NAN_BITS_F64);
+        assert_eq!(F64_TABLE[1], F64(f64::NEG_INFINITY));
+        assert_eq!(F64_TABLE[2].0.to_bits(), 0x8000_0000_0000_0000);
+        assert_eq!(F64_TABLE[3], F64(2.5e-3));
+        assert_eq!(F64_TABLE[4], F64(f64::INFINITY));
+        assert!(F64_TABLE.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    fn panic_message<F: FnOnce() + std::panic::UnwindSafe>(f: F) -> String {
+        let payload = std::panic::catch_unwind(f).unwrap_err();
+        match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload.downcast::<&str>().unwrap().to_string(),
+        }
+    }
+
+    #[test]
+    fn assert_total_eq_passes() {
+        assert_total_eq!(f32::NAN, -f32::NAN);
+        assert_total_eq!(F32(f32::from_bits(0x7f80_0001)), f32::NAN);
+        assert_total_eq!(0.0f64, -0.0);
+        assert_total_eq!(F64(1.5), F64(1.5), "with a message {}", 1);
+        assert_total_ne!(1.0f64, 1.0 + f64::EPSILON);
+        assert_total_ne!(F32(f32::NAN), f32::NEG_INFINITY);
+        assert_total_ne!(0.0f32, f32::from_bits(1), "subnormal");
+    }
+
+    #[test]
+    fn assert_total_eq_fails() {
+        let message = panic_message(|| assert_total_eq!(1.0f64, 1.0 + f64::EPSILON));
+        assert!(message.contains("left == right"));
+        assert!(message.contains("0x3ff0000000000000"));
+        assert!(message.contains("0x3ff0000000000001"));
+        assert!(!message.contains("note"));
+
+        let message = panic_message(|| assert_total_eq!(F32(f32::NAN), 0.0, "custom {}", 42));
+        assert!(message.contains("custom 42"));
+        assert!(message.contains("NaN (0x7fc00000)"));
+        assert!(message.contains("0 (0x00000000)"));
+    }
+
+    #[test]
+    fn assert_total_ne_fails() {
+        let message = panic_message(|| assert_total_ne!(0.0f32, -0.0));
+        assert!(message.contains("left != right"));
+        assert!(message.contains("0x80000000"));
+        assert!(message.contains("sign of zero"));
+
+        let message = panic_message(|| assert_total_ne!(f64::NAN, -f64::NAN));
+        assert!(message.contains("0xfff8000000000000"));
+        assert!(message.contains("both operands are NAN"));
+
+        let message = panic_message(|| assert_total_ne!(F64(2.0), 2.0, "same"));
+        assert!(message.contains("failed under eq-float semantics: same"));
+        assert!(!message.contains("note"));
+    }
+}
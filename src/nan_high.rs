@@ -0,0 +1,221 @@
+//! Wrappers that work exactly like `F32` and `F64`, except that `NAN` sorts above all other
+//! values (including positive infinity) instead of below them.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::{F32, F64};
+
+/// An alias for `NanHigh32`.
+pub type F32NanHigh = NanHigh32;
+
+/// An alias for `NanHigh64`.
+pub type F64NanHigh = NanHigh64;
+
+/// A wrapper around `f32` like `F32`, except that `NAN` is the greatest value.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct NanHigh32(pub f32);
+
+/// This works like `PartialEq` on `f32`, except that `NAN == NAN` is true.
+impl PartialEq for NanHigh32 {
+    fn eq(&self, other: &Self) -> bool {
+        F32(self.0) == F32(other.0)
+    }
+}
+
+impl Eq for NanHigh32 {}
+
+/// This works like `PartialOrd` on `f32`, except that `NAN` sorts above all other floats
+/// (and is equal to another NAN). This always returns a `Some`.
+impl PartialOrd for NanHigh32 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// This works like `PartialOrd` on `f32`, except that `NAN` sorts above all other floats
+/// (and is equal to another NAN).
+impl Ord for NanHigh32 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or_else(|| {
+            if self.0.is_nan() && !other.0.is_nan() {
+                Ordering::Greater
+            } else if !self.0.is_nan() && other.0.is_nan() {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+    }
+}
+
+/// Hashes exactly like `F32`.
+impl Hash for NanHigh32 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        F32(self.0).hash(state);
+    }
+}
+
+impl From<NanHigh32> for f32 {
+    fn from(f: NanHigh32) -> Self {
+        f.0
+    }
+}
+
+impl From<f32> for NanHigh32 {
+    fn from(f: f32) -> Self {
+        NanHigh32(f)
+    }
+}
+
+impl From<NanHigh32> for F32 {
+    fn from(f: NanHigh32) -> Self {
+        F32(f.0)
+    }
+}
+
+impl From<F32> for NanHigh32 {
+    fn from(f: F32) -> Self {
+        NanHigh32(f.0)
+    }
+}
+
+impl fmt::Display for NanHigh32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A wrapper around `f64` like `F64`, except that `NAN` is the greatest value.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct NanHigh64(pub f64);
+
+/// This works like `PartialEq` on `f64`, except that `NAN == NAN` is true.
+impl PartialEq for NanHigh64 {
+    fn eq(&self, other: &Self) -> bool {
+        F64(self.0) == F64(other.0)
+    }
+}
+
+impl Eq for NanHigh64 {}
+
+/// This works like `PartialOrd` on `f64`, except that `NAN` sorts above all other floats
+/// (and is equal to another NAN). This always returns a `Some`.
+impl PartialOrd for NanHigh64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// This works like `PartialOrd` on `f64`, except that `NAN` sorts above all other floats
+/// (and is equal to another NAN).
+impl Ord for NanHigh64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or_else(|| {
+            if self.0.is_nan() && !other.0.is_nan() {
+                Ordering::Greater
+            } else if !self.0.is_nan() && other.0.is_nan() {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+    }
+}
+
+/// Hashes exactly like `F64`.
+impl Hash for NanHigh64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        F64(self.0).hash(state);
+    }
+}
+
+impl From<NanHigh64> for f64 {
+    fn from(f: NanHigh64) -> Self {
+        f.0
+    }
+}
+
+impl From<f64> for NanHigh64 {
+    fn from(f: f64) -> Self {
+        NanHigh64(f)
+    }
+}
+
+impl From<NanHigh64> for F64 {
+    fn from(f: NanHigh64) -> Self {
+        F64(f.0)
+    }
+}
+
+impl From<F64> for NanHigh64 {
+    fn from(f: F64) -> Self {
+        NanHigh64(f.0)
+    }
+}
+
+impl fmt::Display for NanHigh64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::{F64NanHigh, NanHigh32, NanHigh64};
+    use crate::{F32, F64};
+
+    fn calculate_hash<T: Hash>(t: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+
+    #[test]
+    fn nan_high32() {
+        assert!(NanHigh32(f32::NAN) > NanHigh32(f32::INFINITY));
+        assert!(NanHigh32(f32::NAN) == NanHigh32(-f32::NAN));
+        assert!(NanHigh32(5.0) < NanHigh32(f32::NAN));
+        assert!(NanHigh32(0.0) == NanHigh32(-0.0));
+        assert!(NanHigh32(-1.0) < NanHigh32(1.0));
+        assert!(calculate_hash(&NanHigh32(f32::NAN)) == calculate_hash(&NanHigh32(-f32::NAN)));
+        assert!(calculate_hash(&NanHigh32(0.0)) == calculate_hash(&NanHigh32(-0.0)));
+    }
+
+    #[test]
+    fn nan_high64() {
+        assert!(NanHigh64(f64::NAN) > NanHigh64(f64::INFINITY));
+        assert!(NanHigh64(f64::NAN) == NanHigh64(-f64::NAN));
+        assert!(NanHigh64(5.0) < NanHigh64(f64::NAN));
+        assert!(NanHigh64(0.0) == NanHigh64(-0.0));
+        assert!(NanHigh64(-1.0) < NanHigh64(1.0));
+        assert!(calculate_hash(&NanHigh64(f64::NAN)) == calculate_hash(&NanHigh64(-f64::NAN)));
+        assert!(calculate_hash(&NanHigh64(0.0)) == calculate_hash(&NanHigh64(-0.0)));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(F32::from(NanHigh32::from(F32(1.5))), F32(1.5));
+        let nan = f64::from_bits(f64::NAN.to_bits() | 1);
+        assert_eq!(F64::from(NanHigh64::from(F64(nan))).0.to_bits(), nan.to_bits());
+        assert_eq!(calculate_hash(&NanHigh64(nan)), calculate_hash(&F64(nan)));
+        assert_eq!(calculate_hash(&NanHigh32(-0.0)), calculate_hash(&F32(0.0)));
+    }
+
+    #[test]
+    fn sort_nan_last() {
+        let mut v: Vec<F64NanHigh> = [f64::NAN, 1.0, f64::INFINITY, -f64::NAN, f64::NEG_INFINITY, -0.0]
+            .iter()
+            .map(|&f| F64NanHigh::from(f))
+            .collect();
+        v.sort();
+        assert_eq!(v[..4], [NanHigh64(f64::NEG_INFINITY), NanHigh64(0.0), NanHigh64(1.0), NanHigh64(f64::INFINITY)]);
+        assert!(v[4..].iter().all(|f| f.0.is_nan()));
+    }
+}
@@ -0,0 +1,276 @@
+//! Conversions between `F32` and `F64`. Widening is lossless and implemented with `From`,
+//! narrowing either fails with a `NarrowingError` (`TryFrom`), rounds as a `RoundMode` specifies,
+//! or rounds to nearest (`F32::from_f64_lossy`). The bulk widening functions need the `alloc`
+//! feature.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::{F32, F64};
+
+/// The error returned when narrowing an `F64` to an `F32` would lose information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NarrowingError {
+    /// The value that has no exact `f32` representation.
+    pub value: F64,
+}
+
+impl fmt::Display for NarrowingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} can not be represented exactly as an f32", self.value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NarrowingError {}
+
+/// How `F64::to_f32_round` rounds values that can not be represented exactly as an `f32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Round to the nearest `f32`, and to the one with an even mantissa on ties. This is what
+    /// `as f32` does.
+    Nearest,
+    /// Round to the nearest `f32` whose magnitude is not greater.
+    TowardZero,
+    /// Round to the nearest `f32` that is not less (toward positive infinity).
+    Up,
+    /// Round to the nearest `f32` that is not greater (toward negative infinity).
+    Down,
+}
+
+impl F64 {
+    /// Narrows to an `F32`, rounding inexact values as specified by `mode`. `NAN` stays `NAN`,
+    /// and values beyond the range of `f32` overflow to infinity unless the mode rounds them
+    /// toward `f32::MAX` or `f32::MIN`.
+    pub fn to_f32_round(self, mode: RoundMode) -> F32 {
+        let nearest = self.0 as f32;
+        if self.0.is_nan() || f64::from(nearest) == self.0 {
+            return F32(nearest);
+        }
+
+        let above = f64::from(nearest) > self.0;
+        F32(match mode {
+            RoundMode::Nearest => nearest,
+            RoundMode::Up if !above => next_up(nearest),
+            RoundMode::Down if above => next_down(nearest),
+            RoundMode::TowardZero if above == (self.0 > 0.0) => {
+                if above {
+                    next_down(nearest)
+                } else {
+                    next_up(nearest)
+                }
+            }
+            _ => nearest,
+        })
+    }
+}
+
+// `From<Vec<F32>> for Vec<F64>` would violate the orphan rules, since neither `From` nor `Vec`
+// is local to this crate, so the bulk conversions are inherent functions instead.
+impl F64 {
+    /// Widens every element losslessly, like `From<F32>` does. `NAN` stays `NAN`, with its sign
+    /// and payload.
+    #[cfg(feature = "alloc")]
+    pub fn widen_vec(v: Vec<F32>) -> Vec<F64> {
+        F64::widen_slice(&v)
+    }
+
+    /// Widens every element losslessly into a new vector, like `From<F32>` does. `NAN` stays
+    /// `NAN`, with its sign and payload.
+    #[cfg(feature = "alloc")]
+    pub fn widen_slice(s: &[F32]) -> Vec<F64> {
+        s.iter().map(|&x| F64::from(x)).collect()
+    }
+}
+
+/// Widens losslessly. `NAN` stays `NAN`.
+impl From<F32> for F64 {
+    fn from(f: F32) -> Self {
+        F64(f.0.into())
+    }
+}
+
+/// Widens losslessly. `NAN` stays `NAN`.
+impl From<f32> for F64 {
+    fn from(f: f32) -> Self {
+        F64(f.into())
+    }
+}
+
+/// Narrows if that is exact, i.e. if widening the result again gives the same value. `NAN`
+/// narrows to an `F32` `NAN`.
+impl TryFrom<F64> for F32 {
+    type Error = NarrowingError;
+
+    fn try_from(f: F64) -> Result<Self, Self::Error> {
+        F32::try_from(f.0)
+    }
+}
+
+/// Narrows if that is exact, i.e. if widening the result again gives the same value. `NAN`
+/// narrows to an `F32` `NAN`.
+impl TryFrom<f64> for F32 {
+    type Error = NarrowingError;
+
+    fn try_from(f: f64) -> Result<Self, Self::Error> {
+        let narrow = f as f32;
+        if f.is_nan() || f64::from(narrow) == f {
+            Ok(F32(narrow))
+        } else {
+            Err(NarrowingError { value: F64(f) })
+        }
+    }
+}
+
+impl F32 {
+    /// Narrows to the nearest `f32` like `as f32` does. Values beyond the range of `f32`
+    /// overflow to infinity.
+    pub fn from_f64_lossy(f: F64) -> Self {
+        F32(f.0 as f32)
+    }
+}
+
+/// Returns the least `f32` greater than `f`, with bit manipulation.
+fn next_up(f: f32) -> f32 {
+    if f.is_nan() || f == f32::INFINITY {
+        f
+    } else if f == 0.0 {
+        f32::from_bits(1)
+    } else if f > 0.0 {
+        f32::from_bits(f.to_bits() + 1)
+    } else {
+        f32::from_bits(f.to_bits() - 1)
+    }
+}
+
+/// Returns the greatest `f32` less than `f`, with bit manipulation.
+fn next_down(f: f32) -> f32 {
+    -next_up(-f)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use super::{NarrowingError, RoundMode};
+    use crate::{F32, F64};
+
+    #[test]
+    fn to_f32_round() {
+        let x = 1.0 + f64::powi(2.0, -30);
+        let above = 1.0 + f32::EPSILON;
+        assert_eq!(F64(x).to_f32_round(RoundMode::Nearest), F32(1.0));
+        assert_eq!(F64(x).to_f32_round(RoundMode::Up), F32(above));
+        assert_eq!(F64(x).to_f32_round(RoundMode::Down), F32(1.0));
+        assert_eq!(F64(x).to_f32_round(RoundMode::TowardZero), F32(1.0));
+
+        assert_eq!(F64(-x).to_f32_round(RoundMode::Up), F32(-1.0));
+        assert_eq!(F64(-x).to_f32_round(RoundMode::Down), F32(-above));
+        assert_eq!(F64(-x).to_f32_round(RoundMode::TowardZero), F32(-1.0));
+
+        let y = f64::from(above) - f64::powi(2.0, -30);
+        assert_eq!(F64(y).to_f32_round(RoundMode::Nearest), F32(above));
+        assert_eq!(F64(y).to_f32_round(RoundMode::TowardZero), F32(1.0));
+        assert_eq!(F64(-y).to_f32_round(RoundMode::TowardZero), F32(-1.0));
+    }
+
+    #[test]
+    fn to_f32_round_special() {
+        for mode in [
+            RoundMode::Nearest,
+            RoundMode::TowardZero,
+            RoundMode::Up,
+            RoundMode::Down,
+        ] {
+            assert_eq!(F64(1.5).to_f32_round(mode), F32(1.5));
+            assert_eq!(F64(f64::NAN).to_f32_round(mode), F32(f32::NAN));
+            assert_eq!(F64(f64::INFINITY).to_f32_round(mode), F32(f32::INFINITY));
+            assert!(F64(-0.0).to_f32_round(mode).0.is_sign_negative());
+        }
+
+        assert_eq!(
+            F64(f64::MAX).to_f32_round(RoundMode::Nearest),
+            F32(f32::INFINITY)
+        );
+        assert_eq!(F64(f64::MAX).to_f32_round(RoundMode::Down), F32(f32::MAX));
+        assert_eq!(
+            F64(f64::MIN).to_f32_round(RoundMode::TowardZero),
+            F32(f32::MIN)
+        );
+        assert_eq!(
+            F64(1e-300).to_f32_round(RoundMode::Up),
+            F32(f32::from_bits(1))
+        );
+        assert_eq!(F64(1e-300).to_f32_round(RoundMode::Down), F32(0.0));
+        assert_eq!(
+            F64(-1e-300).to_f32_round(RoundMode::Down),
+            F32(-f32::from_bits(1))
+        );
+    }
+
+    #[test]
+    fn widen() {
+        assert_eq!(F64::from(F32(0.1)), F64(f64::from(0.1f32)));
+        assert_eq!(F64::from(0.5f32), F64(0.5));
+        assert!(F64::from(F32(f32::NAN)).0.is_nan());
+        assert!(F64::from(F32(-0.0)).0.is_sign_negative());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn widen_bulk() {
+        let narrow = vec![F32(1.5), F32(-0.0), F32(f32::NAN), F32(0.1), F32(f32::NEG_INFINITY)];
+        let wide = F64::widen_slice(&narrow);
+        assert_eq!(wide.len(), narrow.len());
+        for (w, n) in wide.iter().zip(&narrow) {
+            assert_eq!(*w, F64::from(*n));
+            assert_eq!(w.0.is_sign_negative(), n.0.is_sign_negative());
+        }
+        assert!(wide[2].0.is_nan());
+        assert_eq!(wide[1].0.to_bits(), 0x8000_0000_0000_0000);
+        assert_eq!(F64::widen_vec(narrow), wide);
+        assert!(F64::widen_vec(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn narrow_exact() {
+        assert_eq!(F32::try_from(F64(1.5)), Ok(F32(1.5)));
+        assert_eq!(F32::try_from(F64(f64::from(0.1f32))), Ok(F32(0.1)));
+        assert_eq!(F32::try_from(F64(f64::INFINITY)), Ok(F32(f32::INFINITY)));
+        assert_eq!(
+            F32::try_from(F64(f64::NEG_INFINITY)),
+            Ok(F32(f32::NEG_INFINITY))
+        );
+        assert!(F32::try_from(F64(f64::NAN)).unwrap().0.is_nan());
+        assert!(F32::try_from(F64(-0.0)).unwrap().0.is_sign_negative());
+        assert_eq!(
+            F32::try_from(F64(0.1)),
+            Err(NarrowingError { value: F64(0.1) })
+        );
+        assert_eq!(
+            F32::try_from(F64(1e300)),
+            Err(NarrowingError { value: F64(1e300) })
+        );
+        assert_eq!(F32::from_f64_lossy(F64(0.1)), F32(0.1));
+        assert_eq!(F32::from_f64_lossy(F64(1e300)), F32(f32::INFINITY));
+    }
+
+    #[test]
+    fn narrow_primitive() {
+        assert_eq!(F32::try_from(0.5f64), Ok(F32(0.5)));
+        assert_eq!(F32::try_from(-16777216.0f64), Ok(F32(-16777216.0)));
+        assert!(F32::try_from(f64::NAN).unwrap().0.is_nan());
+        assert_eq!(
+            F32::try_from(16777217.0f64),
+            Err(NarrowingError {
+                value: F64(16777217.0)
+            })
+        );
+        assert_eq!(
+            F32::try_from(1e-50f64),
+            Err(NarrowingError { value: F64(1e-50) })
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! Conversions between `ndarray` arrays of primitives and of wrappers, without copying. These
+//! are sound since the wrappers are `#[repr(transparent)]`. Since only the element type changes,
+//! views of any layout (including strided and transposed ones) can be converted.
+
+use ndarray::{Array, ArrayView, ArrayViewMut, Dimension};
+
+use crate::{F32, F64};
+
+impl F32 {
+    /// Views an array view of `f32` as an array view of `F32`.
+    pub fn wrap_array_view<D: Dimension>(view: ArrayView<'_, f32, D>) -> ArrayView<'_, F32, D> {
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`, and the returned
+        // view borrows the same data for the same lifetime.
+        unsafe { view.raw_view().cast::<F32>().deref_into_view() }
+    }
+
+    /// Views a mutable array view of `f32` as a mutable array view of `F32`.
+    pub fn wrap_array_view_mut<D: Dimension>(
+        mut view: ArrayViewMut<'_, f32, D>,
+    ) -> ArrayViewMut<'_, F32, D> {
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`, and the returned
+        // view borrows the same data for the same lifetime.
+        unsafe { view.raw_view_mut().cast::<F32>().deref_into_view_mut() }
+    }
+
+    /// Views an array view of `F32` as an array view of `f32`.
+    pub fn unwrap_array_view<D: Dimension>(view: ArrayView<'_, F32, D>) -> ArrayView<'_, f32, D> {
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`, and the returned
+        // view borrows the same data for the same lifetime.
+        unsafe { view.raw_view().cast::<f32>().deref_into_view() }
+    }
+
+    /// Views a mutable array view of `F32` as a mutable array view of `f32`.
+    pub fn unwrap_array_view_mut<D: Dimension>(
+        mut view: ArrayViewMut<'_, F32, D>,
+    ) -> ArrayViewMut<'_, f32, D> {
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`, and the returned
+        // view borrows the same data for the same lifetime.
+        unsafe { view.raw_view_mut().cast::<f32>().deref_into_view_mut() }
+    }
+
+    /// Converts an array of `f32` into an array of `F32`, reusing the allocation if the array is
+    /// in standard layout (and copying it into standard layout otherwise).
+    pub fn wrap_array<D: Dimension>(array: Array<f32, D>) -> Array<F32, D> {
+        let dim = array.raw_dim();
+        let v = into_standard_vec(array);
+        Array::from_shape_vec(dim, F32::wrap_vec(v)).unwrap()
+    }
+
+    /// Converts an array of `F32` into an array of `f32`, reusing the allocation if the array is
+    /// in standard layout (and copying it into standard layout otherwise).
+    pub fn unwrap_array<D: Dimension>(array: Array<F32, D>) -> Array<f32, D> {
+        let dim = array.raw_dim();
+        let v = into_standard_vec(array);
+        Array::from_shape_vec(dim, F32::unwrap_vec(v)).unwrap()
+    }
+}
+
+impl F64 {
+    /// Views an array view of `f64` as an array view of `F64`.
+    pub fn wrap_array_view<D: Dimension>(view: ArrayView<'_, f64, D>) -> ArrayView<'_, F64, D> {
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`, and the returned
+        // view borrows the same data for the same lifetime.
+        unsafe { view.raw_view().cast::<F64>().deref_into_view() }
+    }
+
+    /// Views a mutable array view of `f64` as a mutable array view of `F64`.
+    pub fn wrap_array_view_mut<D: Dimension>(
+        mut view: ArrayViewMut<'_, f64, D>,
+    ) -> ArrayViewMut<'_, F64, D> {
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`, and the returned
+        // view borrows the same data for the same lifetime.
+        unsafe { view.raw_view_mut().cast::<F64>().deref_into_view_mut() }
+    }
+
+    /// Views an array view of `F64` as an array view of `f64`.
+    pub fn unwrap_array_view<D: Dimension>(view: ArrayView<'_, F64, D>) -> ArrayView<'_, f64, D> {
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`, and the returned
+        // view borrows the same data for the same lifetime.
+        unsafe { view.raw_view().cast::<f64>().deref_into_view() }
+    }
+
+    /// Views a mutable array view of `F64` as a mutable array view of `f64`.
+    pub fn unwrap_array_view_mut<D: Dimension>(
+        mut view: ArrayViewMut<'_, F64, D>,
+    ) -> ArrayViewMut<'_, f64, D> {
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`, and the returned
+        // view borrows the same data for the same lifetime.
+        unsafe { view.raw_view_mut().cast::<f64>().deref_into_view_mut() }
+    }
+
+    /// Converts an array of `f64` into an array of `F64`, reusing the allocation if the array is
+    /// in standard layout (and copying it into standard layout otherwise).
+    pub fn wrap_array<D: Dimension>(array: Array<f64, D>) -> Array<F64, D> {
+        let dim = array.raw_dim();
+        let v = into_standard_vec(array);
+        Array::from_shape_vec(dim, F64::wrap_vec(v)).unwrap()
+    }
+
+    /// Converts an array of `F64` into an array of `f64`, reusing the allocation if the array is
+    /// in standard layout (and copying it into standard layout otherwise).
+    pub fn unwrap_array<D: Dimension>(array: Array<F64, D>) -> Array<f64, D> {
+        let dim = array.raw_dim();
+        let v = into_standard_vec(array);
+        Array::from_shape_vec(dim, F64::unwrap_vec(v)).unwrap()
+    }
+}
+
+/// Returns the elements of the array in standard (row-major) order, reusing the allocation of the
+/// array if it already is in standard layout.
+fn into_standard_vec<A: Clone, D: Dimension>(array: Array<A, D>) -> Vec<A> {
+    let array = if array.is_standard_layout() {
+        array
+    } else {
+        array.as_standard_layout().into_owned()
+    };
+    let len = array.len();
+    // In standard layout, the elements are contiguous and in order, starting at the offset.
+    let (mut v, offset) = array.into_raw_vec_and_offset();
+    let offset = offset.unwrap_or(0);
+    v.truncate(offset + len);
+    v.drain(..offset);
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, s, ArrayView1};
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn sort_rows() {
+        let mut a = array![[3.0f32, 1.0], [f32::NAN, 2.0], [1.0, 5.0], [1.0, -0.0]];
+
+        let mut view = F32::wrap_array_view_mut(a.view_mut());
+        let mut rows: Vec<Vec<F32>> = view.rows().into_iter().map(|row| row.to_vec()).collect();
+        rows.sort();
+        for (mut row, sorted) in view.rows_mut().into_iter().zip(&rows) {
+            row.assign(&ArrayView1::from(&sorted[..]));
+        }
+
+        let expected = array![[f32::NAN, 2.0], [1.0, 0.0], [1.0, 5.0], [3.0, 1.0]];
+        assert_eq!(
+            F32::wrap_array_view(a.view()),
+            F32::wrap_array_view(expected.view())
+        );
+        assert!(a[[0, 0]].is_nan());
+    }
+
+    #[test]
+    fn strided_views() {
+        let a = array![[1.0f64, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let wrapped = F64::wrap_array_view(a.slice(s![.., ..;2]));
+        assert_eq!(wrapped, array![[F64(1.0), F64(3.0)], [F64(4.0), F64(6.0)]]);
+
+        let transposed = F64::wrap_array_view(a.t());
+        assert_eq!(transposed[[2, 1]], F64(6.0));
+        assert_eq!(F64::unwrap_array_view(transposed), a.t());
+    }
+
+    #[test]
+    fn owned_arrays() {
+        let a = array![[1.0f32, -0.0], [f32::NAN, 4.0]];
+        let wrapped = F32::wrap_array(a.clone());
+        assert_eq!(
+            wrapped,
+            array![[F32(1.0), F32(0.0)], [F32(f32::NAN), F32(4.0)]]
+        );
+        assert_eq!(wrapped[[0, 1]].0.to_bits(), (-0.0f32).to_bits());
+
+        let transposed = F32::wrap_array(a.reversed_axes());
+        assert_eq!(
+            transposed,
+            array![[F32(1.0), F32(f32::NAN)], [F32(0.0), F32(4.0)]]
+        );
+
+        let sliced = F64::wrap_array(array![[1.0, 2.0], [3.0, 4.0]].slice_move(s![1.., ..]));
+        assert_eq!(F64::unwrap_array(sliced), array![[3.0, 4.0]]);
+    }
+}
@@ -0,0 +1,202 @@
+//! Wrappers around the unstable `f16` and `f128` primitives, with the same semantics as `F32` and
+//! `F64`. This module requires a nightly compiler.
+//!
+//! These live in their own module so that they don't clash with the `F16` wrapper around
+//! `half::f16` provided by the `half` feature.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::{CanonicalBits, EqFloat, F32, F64};
+
+/// The bits of the `f16` `NAN` that stands in for all `NAN`s wherever this crate needs a single
+/// representation, e.g. for hashing.
+pub const CANONICAL_NAN_BITS_F16: u16 = 0x7e00;
+
+/// The bits of the `f128` `NAN` that stands in for all `NAN`s wherever this crate needs a single
+/// representation, e.g. for hashing.
+pub const CANONICAL_NAN_BITS_F128: u128 = 0x7fff_8000_0000_0000_0000_0000_0000_0000;
+
+/// A wrapper around `f16` with the same semantics as `F32` and `F64`.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct F16(pub f16);
+
+impl F16 {
+    /// Returns the `NAN` whose bits are `CANONICAL_NAN_BITS_F16`.
+    pub const fn canonical_nan() -> Self {
+        F16(f16::from_bits(CANONICAL_NAN_BITS_F16))
+    }
+}
+
+/// This works like `PartialEq` on `f16`, except that `NAN == NAN` is true.
+impl PartialEq for F16 {
+    fn eq(&self, other: &Self) -> bool {
+        EqFloat(self.0) == EqFloat(other.0)
+    }
+}
+
+impl Eq for F16 {}
+
+/// This works like `PartialOrd` on `f16`, except that `NAN` sorts below all other floats
+/// (and is equal to another NAN). This always returns a `Some`.
+impl PartialOrd for F16 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// This works like `PartialOrd` on `f16`, except that `NAN` sorts below all other floats
+/// (and is equal to another NAN).
+impl Ord for F16 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        EqFloat(self.0).cmp(&EqFloat(other.0))
+    }
+}
+
+impl Hash for F16 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        EqFloat(self.0).hash(state);
+    }
+}
+
+impl CanonicalBits for f16 {
+    type Bits = u16;
+
+    fn is_nan(self) -> bool {
+        f16::is_nan(self)
+    }
+
+    fn canonical_bits(self) -> u16 {
+        if self.is_nan() {
+            CANONICAL_NAN_BITS_F16
+        } else if self == 0.0 { // catches both positive and negative zero
+            0
+        } else {
+            self.to_bits()
+        }
+    }
+}
+
+impl From<F16> for f16 {
+    fn from(f: F16) -> Self {
+        f.0
+    }
+}
+
+impl From<f16> for F16 {
+    fn from(f: f16) -> Self {
+        F16(f)
+    }
+}
+
+/// Widens losslessly, `NAN` stays `NAN`.
+impl From<F16> for F32 {
+    fn from(f: F16) -> Self {
+        F32(f.0 as f32)
+    }
+}
+
+impl fmt::Display for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A wrapper around `f128` with the same semantics as `F32` and `F64`.
+///
+/// The standard library can not format `f128` in decimal yet, so this does not implement
+/// `Display`.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct F128(pub f128);
+
+impl F128 {
+    /// Returns the `NAN` whose bits are `CANONICAL_NAN_BITS_F128`.
+    pub const fn canonical_nan() -> Self {
+        F128(f128::from_bits(CANONICAL_NAN_BITS_F128))
+    }
+}
+
+/// This works like `PartialEq` on `f128`, except that `NAN == NAN` is true.
+impl PartialEq for F128 {
+    fn eq(&self, other: &Self) -> bool {
+        EqFloat(self.0) == EqFloat(other.0)
+    }
+}
+
+impl Eq for F128 {}
+
+/// This works like `PartialOrd` on `f128`, except that `NAN` sorts below all other floats
+/// (and is equal to another NAN). This always returns a `Some`.
+impl PartialOrd for F128 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// This works like `PartialOrd` on `f128`, except that `NAN` sorts below all other floats
+/// (and is equal to another NAN).
+impl Ord for F128 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        EqFloat(self.0).cmp(&EqFloat(other.0))
+    }
+}
+
+impl Hash for F128 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        EqFloat(self.0).hash(state);
+    }
+}
+
+impl CanonicalBits for f128 {
+    type Bits = u128;
+
+    fn is_nan(self) -> bool {
+        f128::is_nan(self)
+    }
+
+    fn canonical_bits(self) -> u128 {
+        if self.is_nan() {
+            CANONICAL_NAN_BITS_F128
+        } else if self == 0.0 { // catches both positive and negative zero
+            0
+        } else {
+            self.to_bits()
+        }
+    }
+}
+
+impl From<F128> for f128 {
+    fn from(f: F128) -> Self {
+        f.0
+    }
+}
+
+impl From<f128> for F128 {
+    fn from(f: f128) -> Self {
+        F128(f)
+    }
+}
+
+/// Widens losslessly, `NAN` stays `NAN`.
+impl From<F64> for F128 {
+    fn from(f: F64) -> Self {
+        F128(f.0.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{F128, F16};
+    use crate::{F32, F64};
+
+    #[test]
+    fn nightly_widening() {
+        assert_eq!(F32::from(F16(1.5)), F32(1.5));
+        assert_eq!(F32::from(F16(f16::NAN)), F32(f32::NAN));
+        assert_eq!(F128::from(F64(-0.25)), F128(-0.25));
+        assert_eq!(F128::from(F64(f64::NAN)), F128(f128::NAN));
+    }
+}
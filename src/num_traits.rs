@@ -0,0 +1,388 @@
+//! `num-traits` support. Everything delegates to the implementations for the inner float.
+//!
+//! Note that `Float::max` and `Float::min` (and the `FloatCore` equivalents) follow the IEEE
+//! semantics of the primitives: they ignore a `NAN` argument. This differs from `Ord::max` and
+//! `Ord::min`, for which `NAN` is the least value. Generic code bounded on `Float` relies on the
+//! IEEE behavior, so that is what these traits provide.
+
+use core::num::FpCategory;
+
+use num_traits::float::FloatCore;
+use num_traits::{Bounded, Float, FromPrimitive, Num, NumCast, One, Signed, ToPrimitive, Zero};
+
+use crate::{F32, F64};
+
+macro_rules! impl_num_traits {
+    ($t:ident, $float:ident) => {
+        impl Zero for $t {
+            fn zero() -> Self {
+                $t(0.0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+        }
+
+        impl One for $t {
+            fn one() -> Self {
+                $t(1.0)
+            }
+        }
+
+        impl Num for $t {
+            type FromStrRadixErr = <$float as Num>::FromStrRadixErr;
+
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                <$float as Num>::from_str_radix(str, radix).map($t)
+            }
+        }
+
+        /// `min_value` is `MIN` (not `NEG_INFINITY`), just like for the primitive.
+        impl Bounded for $t {
+            fn min_value() -> Self {
+                $t(<$float as Bounded>::min_value())
+            }
+
+            fn max_value() -> Self {
+                $t(<$float as Bounded>::max_value())
+            }
+        }
+
+        impl Signed for $t {
+            fn abs(&self) -> Self {
+                $t(Signed::abs(&self.0))
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                $t(Signed::abs_sub(&self.0, &other.0))
+            }
+
+            fn signum(&self) -> Self {
+                $t(Signed::signum(&self.0))
+            }
+
+            fn is_positive(&self) -> bool {
+                Signed::is_positive(&self.0)
+            }
+
+            fn is_negative(&self) -> bool {
+                Signed::is_negative(&self.0)
+            }
+        }
+
+        impl ToPrimitive for $t {
+            fn to_isize(&self) -> Option<isize> {
+                self.0.to_isize()
+            }
+
+            fn to_i8(&self) -> Option<i8> {
+                self.0.to_i8()
+            }
+
+            fn to_i16(&self) -> Option<i16> {
+                self.0.to_i16()
+            }
+
+            fn to_i32(&self) -> Option<i32> {
+                self.0.to_i32()
+            }
+
+            fn to_i64(&self) -> Option<i64> {
+                self.0.to_i64()
+            }
+
+            fn to_i128(&self) -> Option<i128> {
+                self.0.to_i128()
+            }
+
+            fn to_usize(&self) -> Option<usize> {
+                self.0.to_usize()
+            }
+
+            fn to_u8(&self) -> Option<u8> {
+                self.0.to_u8()
+            }
+
+            fn to_u16(&self) -> Option<u16> {
+                self.0.to_u16()
+            }
+
+            fn to_u32(&self) -> Option<u32> {
+                self.0.to_u32()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.0.to_u64()
+            }
+
+            fn to_u128(&self) -> Option<u128> {
+                self.0.to_u128()
+            }
+
+            fn to_f32(&self) -> Option<f32> {
+                self.0.to_f32()
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                self.0.to_f64()
+            }
+        }
+
+        impl FromPrimitive for $t {
+            fn from_isize(n: isize) -> Option<Self> {
+                <$float as FromPrimitive>::from_isize(n).map($t)
+            }
+
+            fn from_i8(n: i8) -> Option<Self> {
+                <$float as FromPrimitive>::from_i8(n).map($t)
+            }
+
+            fn from_i16(n: i16) -> Option<Self> {
+                <$float as FromPrimitive>::from_i16(n).map($t)
+            }
+
+            fn from_i32(n: i32) -> Option<Self> {
+                <$float as FromPrimitive>::from_i32(n).map($t)
+            }
+
+            fn from_i64(n: i64) -> Option<Self> {
+                <$float as FromPrimitive>::from_i64(n).map($t)
+            }
+
+            fn from_i128(n: i128) -> Option<Self> {
+                <$float as FromPrimitive>::from_i128(n).map($t)
+            }
+
+            fn from_usize(n: usize) -> Option<Self> {
+                <$float as FromPrimitive>::from_usize(n).map($t)
+            }
+
+            fn from_u8(n: u8) -> Option<Self> {
+                <$float as FromPrimitive>::from_u8(n).map($t)
+            }
+
+            fn from_u16(n: u16) -> Option<Self> {
+                <$float as FromPrimitive>::from_u16(n).map($t)
+            }
+
+            fn from_u32(n: u32) -> Option<Self> {
+                <$float as FromPrimitive>::from_u32(n).map($t)
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                <$float as FromPrimitive>::from_u64(n).map($t)
+            }
+
+            fn from_u128(n: u128) -> Option<Self> {
+                <$float as FromPrimitive>::from_u128(n).map($t)
+            }
+
+            fn from_f32(n: f32) -> Option<Self> {
+                <$float as FromPrimitive>::from_f32(n).map($t)
+            }
+
+            fn from_f64(n: f64) -> Option<Self> {
+                <$float as FromPrimitive>::from_f64(n).map($t)
+            }
+        }
+
+        impl NumCast for $t {
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                <$float as NumCast>::from(n).map($t)
+            }
+        }
+    };
+}
+
+impl_num_traits!(F32, f32);
+impl_num_traits!(F64, f64);
+
+macro_rules! delegate {
+    ($trait:ident, $float:ident, $t:ident, consts: $($const:ident)*) => {
+        $(
+            fn $const() -> Self {
+                $t(<$float as $trait>::$const())
+            }
+        )*
+    };
+    ($trait:ident, $float:ident, $t:ident, unary: $($unary:ident)*) => {
+        $(
+            fn $unary(self) -> Self {
+                $t(<$float as $trait>::$unary(self.0))
+            }
+        )*
+    };
+    ($trait:ident, $float:ident, $t:ident, binary: $($binary:ident)*) => {
+        $(
+            fn $binary(self, other: Self) -> Self {
+                $t(<$float as $trait>::$binary(self.0, other.0))
+            }
+        )*
+    };
+    ($trait:ident, $float:ident, $t:ident, predicates: $($predicate:ident)*) => {
+        $(
+            fn $predicate(self) -> bool {
+                <$float as $trait>::$predicate(self.0)
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float_traits {
+    ($t:ident, $float:ident) => {
+        impl FloatCore for $t {
+            delegate!(FloatCore, $float, $t, consts:
+                nan infinity neg_infinity neg_zero min_value min_positive_value max_value epsilon);
+            delegate!(FloatCore, $float, $t, unary:
+                floor ceil round trunc fract abs signum recip to_degrees to_radians);
+            delegate!(FloatCore, $float, $t, binary: max min);
+            delegate!(FloatCore, $float, $t, predicates:
+                is_nan is_infinite is_finite is_normal is_sign_positive is_sign_negative);
+
+            fn classify(self) -> FpCategory {
+                FloatCore::classify(self.0)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                $t(FloatCore::powi(self.0, n))
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                FloatCore::integer_decode(self.0)
+            }
+        }
+
+        impl Float for $t {
+            delegate!(Float, $float, $t, consts:
+                nan infinity neg_infinity neg_zero min_value min_positive_value max_value epsilon);
+            delegate!(Float, $float, $t, unary:
+                floor ceil round trunc fract abs signum recip sqrt exp exp2 ln log2 log10 cbrt
+                sin cos tan asin acos atan exp_m1 ln_1p sinh cosh tanh asinh acosh atanh
+                to_degrees to_radians);
+            delegate!(Float, $float, $t, binary: powf log max min abs_sub hypot atan2);
+            delegate!(Float, $float, $t, predicates:
+                is_nan is_infinite is_finite is_normal is_sign_positive is_sign_negative);
+
+            fn classify(self) -> FpCategory {
+                Float::classify(self.0)
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                $t(Float::mul_add(self.0, a.0, b.0))
+            }
+
+            fn powi(self, n: i32) -> Self {
+                $t(Float::powi(self.0, n))
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = Float::sin_cos(self.0);
+                ($t(sin), $t(cos))
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                Float::integer_decode(self.0)
+            }
+        }
+    };
+}
+
+impl_float_traits!(F32, f32);
+impl_float_traits!(F64, f64);
+
+#[cfg(test)]
+mod tests {
+    use std::num::FpCategory;
+
+    use num_traits::float::FloatCore;
+    use num_traits::{Bounded, Float, FromPrimitive, Num, NumCast, Signed, ToPrimitive};
+
+    use crate::{F32, F64};
+
+    fn mean_variance<T: Num + FromPrimitive + Copy>(xs: &[T]) -> (T, T) {
+        let n = T::from_usize(xs.len()).unwrap();
+        let mean = xs.iter().fold(T::zero(), |acc, &x| acc + x) / n;
+        let variance = xs
+            .iter()
+            .fold(T::zero(), |acc, &x| acc + (x - mean) * (x - mean))
+            / n;
+        (mean, variance)
+    }
+
+    #[test]
+    fn num_traits_generic_stats() {
+        let raw32: [f32; 5] = [1.5, 2.25, -3.0, 10.0, 0.125];
+        let raw64: [f64; 5] = [1.5, 2.25, -3.0, 10.0, 0.125];
+
+        let (mean, variance) = mean_variance(&raw32.map(F32));
+        let (raw_mean, raw_variance) = mean_variance(&raw32);
+        assert_eq!(mean.0.to_bits(), raw_mean.to_bits());
+        assert_eq!(variance.0.to_bits(), raw_variance.to_bits());
+
+        let (mean, variance) = mean_variance(&raw64.map(F64));
+        let (raw_mean, raw_variance) = mean_variance(&raw64);
+        assert_eq!(mean.0.to_bits(), raw_mean.to_bits());
+        assert_eq!(variance.0.to_bits(), raw_variance.to_bits());
+    }
+
+    #[test]
+    fn num_traits_delegation() {
+        assert_eq!(<F32 as Bounded>::min_value(), F32(f32::MIN));
+        assert_eq!(<F64 as Bounded>::max_value(), F64(f64::MAX));
+        assert_eq!(F64::from_str_radix("1.5", 10).unwrap(), F64(1.5));
+        assert_eq!(<F32 as NumCast>::from(3u8), Some(F32(3.0)));
+        assert_eq!(F64(-2.5).to_i64(), Some(-2));
+        assert_eq!(F64(f64::NAN).to_i64(), None);
+        assert_eq!(Signed::abs(&F32(-2.5)), F32(2.5));
+        assert_eq!(Signed::signum(&F64(f64::NAN)), F64(f64::NAN));
+    }
+
+    fn float_ops<T: Float>(x: T) -> (T, (u64, i16, i8), FpCategory) {
+        let two = T::one() + T::one();
+        (x.mul_add(two, T::one()), x.integer_decode(), x.classify())
+    }
+
+    fn float_core_ops<T: FloatCore>(x: T) -> (T, (u64, i16, i8), FpCategory) {
+        (x.powi(2), x.integer_decode(), x.classify())
+    }
+
+    #[test]
+    fn num_traits_float() {
+        for raw in [1.5, -0.0, 1e-310, f64::INFINITY, f64::NAN] {
+            let (a, decoded, category) = float_ops(F64(raw));
+            let (raw_a, raw_decoded, raw_category) = float_ops(raw);
+            assert_eq!(a.0.to_bits(), raw_a.to_bits());
+            assert_eq!(decoded, raw_decoded);
+            assert_eq!(category, raw_category);
+
+            let (a, decoded, category) = float_core_ops(F64(raw));
+            let (raw_a, raw_decoded, raw_category) = float_core_ops(raw);
+            assert_eq!(a.0.to_bits(), raw_a.to_bits());
+            assert_eq!(decoded, raw_decoded);
+            assert_eq!(category, raw_category);
+        }
+
+        for raw in [1.5f32, -0.0, 1e-40, f32::INFINITY, f32::NAN] {
+            let (a, decoded, category) = float_ops(F32(raw));
+            let (raw_a, raw_decoded, raw_category) = float_ops(raw);
+            assert_eq!(a.0.to_bits(), raw_a.to_bits());
+            assert_eq!(decoded, raw_decoded);
+            assert_eq!(category, raw_category);
+
+            let (a, decoded, category) = float_core_ops(F32(raw));
+            let (raw_a, raw_decoded, raw_category) = float_core_ops(raw);
+            assert_eq!(a.0.to_bits(), raw_a.to_bits());
+            assert_eq!(decoded, raw_decoded);
+            assert_eq!(category, raw_category);
+        }
+    }
+
+    #[test]
+    fn num_traits_float_min_max_ignore_nan() {
+        assert_eq!(Float::min(F64(f64::NAN), F64(1.0)), F64(1.0));
+        assert_eq!(Ord::min(F64(f64::NAN), F64(1.0)), F64(f64::NAN));
+        assert_eq!(FloatCore::min(F32(1.0), F32(f32::NAN)), F32(1.0));
+        assert_eq!(Ord::min(F32(1.0), F32(f32::NAN)), F32(f32::NAN));
+    }
+}
@@ -0,0 +1,113 @@
+//! Conversions between the wrappers of this crate and those of the `ordered-float` crate. A
+//! conversion never changes the bits of the float, only the wrapper.
+//!
+//! The orderings agree on everything but `NAN`:
+//!
+//! | | `F32`/`F64` | `OrderedFloat` | `NotNan` |
+//! |-|-|-|-|
+//! | `NAN` | less than all other values | greater than all other values | not representable |
+//! | `NAN == NAN` | `true` | `true` | - |
+//! | `-0.0 == 0.0` | `true` | `true` | `true` |
+
+use core::convert::TryFrom;
+
+use ordered_float::{FloatIsNan, NotNan, OrderedFloat};
+
+use crate::{F32, F64};
+
+impl From<OrderedFloat<f32>> for F32 {
+    fn from(f: OrderedFloat<f32>) -> Self {
+        F32(f.0)
+    }
+}
+
+/// Note that `OrderedFloat` sorts `NAN` above all other values, whereas `F32` sorts it below.
+impl From<F32> for OrderedFloat<f32> {
+    fn from(f: F32) -> Self {
+        OrderedFloat(f.0)
+    }
+}
+
+impl From<NotNan<f32>> for F32 {
+    fn from(f: NotNan<f32>) -> Self {
+        F32(f.into_inner())
+    }
+}
+
+impl TryFrom<F32> for NotNan<f32> {
+    type Error = FloatIsNan;
+
+    fn try_from(f: F32) -> Result<Self, Self::Error> {
+        NotNan::new(f.0)
+    }
+}
+
+impl From<OrderedFloat<f64>> for F64 {
+    fn from(f: OrderedFloat<f64>) -> Self {
+        F64(f.0)
+    }
+}
+
+/// Note that `OrderedFloat` sorts `NAN` above all other values, whereas `F64` sorts it below.
+impl From<F64> for OrderedFloat<f64> {
+    fn from(f: F64) -> Self {
+        OrderedFloat(f.0)
+    }
+}
+
+impl From<NotNan<f64>> for F64 {
+    fn from(f: NotNan<f64>) -> Self {
+        F64(f.into_inner())
+    }
+}
+
+impl TryFrom<F64> for NotNan<f64> {
+    type Error = FloatIsNan;
+
+    fn try_from(f: F64) -> Result<Self, Self::Error> {
+        NotNan::new(f.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ordered_float::{NotNan, OrderedFloat};
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn ordered_float_round_trip() {
+        for bits in [f32::NAN.to_bits(), (-f32::NAN).to_bits(), (-0.0f32).to_bits()] {
+            let f = F32(f32::from_bits(bits));
+            let ordered = OrderedFloat::from(f);
+            assert_eq!(ordered.0.to_bits(), bits);
+            assert_eq!(F32::from(ordered).0.to_bits(), bits);
+        }
+
+        for bits in [f64::NAN.to_bits(), (-f64::NAN).to_bits(), (-0.0f64).to_bits()] {
+            let f = F64(f64::from_bits(bits));
+            let ordered = OrderedFloat::from(f);
+            assert_eq!(ordered.0.to_bits(), bits);
+            assert_eq!(F64::from(ordered).0.to_bits(), bits);
+        }
+    }
+
+    #[test]
+    fn ordered_float_nan_position() {
+        assert!(F64(f64::NAN) < F64(1.0));
+        assert!(OrderedFloat::from(F64(f64::NAN)) > OrderedFloat::from(F64(1.0)));
+    }
+
+    #[test]
+    fn not_nan_round_trip() {
+        let f = NotNan::<f32>::try_from(F32(-0.0)).unwrap();
+        assert_eq!(F32::from(f).0.to_bits(), (-0.0f32).to_bits());
+        assert!(NotNan::<f32>::try_from(F32(f32::NAN)).is_err());
+
+        let f = NotNan::<f64>::try_from(F64(-0.0)).unwrap();
+        assert_eq!(F64::from(f).0.to_bits(), (-0.0f64).to_bits());
+        assert!(NotNan::<f64>::try_from(F64(f64::NAN)).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+//! `FromStr` impls for the wrappers, and `parse_finite`, which also rejects `NAN` and the
+//! infinities and reports failures as a `ParseError`.
+
+use core::fmt;
+use core::num::ParseFloatError;
+use core::str::FromStr;
+
+use crate::{F32, F64};
+
+/// The error returned by `F32::parse_finite` and `F64::parse_finite`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string is not a float.
+    Invalid(ParseFloatError),
+    /// The string is a float, but it is `NAN` or infinite.
+    NonFinite,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Invalid(err) => err.fmt(f),
+            ParseError::NonFinite => write!(f, "float is not finite"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Invalid(err) => Some(err),
+            ParseError::NonFinite => None,
+        }
+    }
+}
+
+/// Parses exactly the strings `f32::from_str` parses, including `NaN`, `inf` and `-inf`.
+impl FromStr for F32 {
+    type Err = ParseFloatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(F32)
+    }
+}
+
+impl F32 {
+    /// Parses a float like `f32::from_str` does, but rejects `NAN` and the infinities.
+    pub fn parse_finite(s: &str) -> Result<F32, ParseError> {
+        match s.parse::<f32>() {
+            Ok(f) if f.is_finite() => Ok(F32(f)),
+            Ok(_) => Err(ParseError::NonFinite),
+            Err(err) => Err(ParseError::Invalid(err)),
+        }
+    }
+}
+
+/// Parses exactly the strings `f64::from_str` parses, including `NaN`, `inf` and `-inf`.
+impl FromStr for F64 {
+    type Err = ParseFloatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(F64)
+    }
+}
+
+impl F64 {
+    /// Parses a float like `f64::from_str` does, but rejects `NAN` and the infinities.
+    pub fn parse_finite(s: &str) -> Result<F64, ParseError> {
+        match s.parse::<f64>() {
+            Ok(f) if f.is_finite() => Ok(F64(f)),
+            Ok(_) => Err(ParseError::NonFinite),
+            Err(err) => Err(ParseError::Invalid(err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+    use crate::{F32, F64};
+
+    #[test]
+    fn parse_finite() {
+        assert_eq!(F32::parse_finite("1.5"), Ok(F32(1.5)));
+        assert_eq!(F32::parse_finite("nan"), Err(ParseError::NonFinite));
+        assert_eq!(F32::parse_finite("-inf"), Err(ParseError::NonFinite));
+        assert!(matches!(F32::parse_finite("abc"), Err(ParseError::Invalid(_))));
+
+        assert_eq!(F64::parse_finite("-2e300"), Ok(F64(-2e300)));
+        assert_eq!(F64::parse_finite("NaN"), Err(ParseError::NonFinite));
+        assert_eq!(F64::parse_finite("inf"), Err(ParseError::NonFinite));
+        assert!(matches!(F64::parse_finite("abc"), Err(ParseError::Invalid(_))));
+    }
+
+    #[test]
+    fn from_str() {
+        let inputs = [
+            "1.5", "-0", "NaN", "nan", "-nan", "inf", "-inf", "infinity", "1e400", "0x10", "",
+            " 1", "1,5", "abc", "+3",
+        ];
+        for input in inputs {
+            assert_eq!(input.parse::<F32>(), input.parse::<f32>().map(F32));
+            assert_eq!(input.parse::<F64>(), input.parse::<f64>().map(F64));
+        }
+
+        assert_eq!("NaN".parse::<F64>().unwrap(), F64::NAN);
+        assert_eq!("-inf".parse::<F32>().unwrap(), F32::NEG_INFINITY);
+        assert!("-0".parse::<F64>().unwrap().0.is_sign_negative());
+    }
+}
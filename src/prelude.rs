@@ -0,0 +1,3 @@
+//! Glob-import this module to bring the wrappers and the extension traits into scope.
+
+pub use crate::{EqFloatExt, EqFloatIteratorExt, IntoEqFloat, F32, F64};
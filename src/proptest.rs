@@ -0,0 +1,208 @@
+//! `proptest` strategies for `F32` and `F64`.
+//!
+//! `any::<F32>()` and `any::<F64>()` cover the full bit space, just like `any_f32()` and
+//! `any_f64()`.
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::sample::select;
+use proptest::strategy::{Map, Strategy};
+
+use crate::{F32, F64};
+
+static SPECIAL_F32: [f32; 17] = [
+    f32::NAN,
+    -f32::NAN,
+    f32::from_bits(0x7f80_0001), // signalling NAN
+    f32::from_bits(0xffa0_0000), // negative signalling NAN
+    f32::from_bits(0x7fc0_1234), // quiet NAN with a payload
+    f32::from_bits(0x7fff_ffff), // quiet NAN with all payload bits set
+    0.0,
+    -0.0,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::MIN,
+    f32::MAX,
+    f32::MIN_POSITIVE,
+    f32::from_bits(1), // smallest positive subnormal
+    f32::EPSILON,
+    1.0 + f32::EPSILON,
+    1.0 - f32::EPSILON / 2.0,
+];
+
+static SPECIAL_F64: [f64; 17] = [
+    f64::NAN,
+    -f64::NAN,
+    f64::from_bits(0x7ff0_0000_0000_0001), // signalling NAN
+    f64::from_bits(0xfff4_0000_0000_0000), // negative signalling NAN
+    f64::from_bits(0x7ff8_0000_0000_1234), // quiet NAN with a payload
+    f64::from_bits(0x7fff_ffff_ffff_ffff), // quiet NAN with all payload bits set
+    0.0,
+    -0.0,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::MIN,
+    f64::MAX,
+    f64::MIN_POSITIVE,
+    f64::from_bits(1), // smallest positive subnormal
+    f64::EPSILON,
+    1.0 + f64::EPSILON,
+    1.0 - f64::EPSILON / 2.0,
+];
+
+/// Any `F32`, drawn uniformly from the full bit space.
+pub fn any_f32() -> impl Strategy<Value = F32> {
+    any::<F32>()
+}
+
+/// Any `F64`, drawn uniformly from the full bit space.
+pub fn any_f64() -> impl Strategy<Value = F64> {
+    any::<F64>()
+}
+
+/// Any finite `F32`, including zeros and subnormals.
+pub fn finite_f32() -> impl Strategy<Value = F32> {
+    use proptest::num::f32::{NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+    (POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO).prop_map(F32)
+}
+
+/// Any finite `F64`, including zeros and subnormals.
+pub fn finite_f64() -> impl Strategy<Value = F64> {
+    use proptest::num::f64::{NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+    (POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO).prop_map(F64)
+}
+
+/// Any `F32` that is not `NAN`, including the infinities.
+pub fn not_nan_f32() -> impl Strategy<Value = F32> {
+    use proptest::num::f32::{INFINITE, NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+    (POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO | INFINITE).prop_map(F32)
+}
+
+/// Any `F64` that is not `NAN`, including the infinities.
+pub fn not_nan_f64() -> impl Strategy<Value = F64> {
+    use proptest::num::f64::{INFINITE, NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+    (POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO | INFINITE).prop_map(F64)
+}
+
+/// The corner cases of `f32`: `NAN`s of both signs and various payloads, signed zeros,
+/// infinities, extreme values, the smallest subnormal and the neighborhood of `1.0`.
+pub fn special_values_f32() -> impl Strategy<Value = F32> {
+    select(&SPECIAL_F32[..]).prop_map(F32)
+}
+
+/// The corner cases of `f64`: `NAN`s of both signs and various payloads, signed zeros,
+/// infinities, extreme values, the smallest subnormal and the neighborhood of `1.0`.
+pub fn special_values_f64() -> impl Strategy<Value = F64> {
+    select(&SPECIAL_F64[..]).prop_map(F64)
+}
+
+fn f32_from_bits(bits: u32) -> F32 {
+    F32(f32::from_bits(bits))
+}
+
+fn f64_from_bits(bits: u64) -> F64 {
+    F64(f64::from_bits(bits))
+}
+
+impl Arbitrary for F32 {
+    type Parameters = ();
+    type Strategy = Map<<u32 as Arbitrary>::Strategy, fn(u32) -> F32>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        any::<u32>().prop_map(f32_from_bits as fn(u32) -> F32)
+    }
+}
+
+impl Arbitrary for F64 {
+    type Parameters = ();
+    type Strategy = Map<<u64 as Arbitrary>::Strategy, fn(u64) -> F64>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        any::<u64>().prop_map(f64_from_bits as fn(u64) -> F64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn calculate_hash<T: Hash>(t: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+
+    // Mixes in the special values, so that equal pairs and triples actually occur.
+    fn interesting_f32() -> impl Strategy<Value = F32> {
+        prop_oneof![any_f32(), finite_f32(), special_values_f32()]
+    }
+
+    fn interesting_f64() -> impl Strategy<Value = F64> {
+        prop_oneof![any_f64(), finite_f64(), special_values_f64()]
+    }
+
+    proptest! {
+        #[test]
+        fn f32_eq_laws(a in interesting_f32(), b in interesting_f32(), c in interesting_f32()) {
+            prop_assert_eq!(a, a);
+            prop_assert_eq!(a == b, b == a);
+            if a == b && b == c {
+                prop_assert_eq!(a, c);
+            }
+        }
+
+        #[test]
+        fn f32_ord_laws(a in interesting_f32(), b in interesting_f32()) {
+            prop_assert_eq!(a.cmp(&b).reverse(), b.cmp(&a));
+            prop_assert_eq!(a.cmp(&b) == Ordering::Equal, a == b);
+            prop_assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+        }
+
+        #[test]
+        fn f32_hash_consistent_with_eq(a in interesting_f32(), b in interesting_f32()) {
+            if a == b {
+                prop_assert_eq!(calculate_hash(&a), calculate_hash(&b));
+            }
+        }
+
+        #[test]
+        fn f32_strategies(a in finite_f32(), b in not_nan_f32()) {
+            prop_assert!(a.0.is_finite());
+            prop_assert!(!b.0.is_nan());
+        }
+
+        #[test]
+        fn f64_eq_laws(a in interesting_f64(), b in interesting_f64(), c in interesting_f64()) {
+            prop_assert_eq!(a, a);
+            prop_assert_eq!(a == b, b == a);
+            if a == b && b == c {
+                prop_assert_eq!(a, c);
+            }
+        }
+
+        #[test]
+        fn f64_ord_laws(a in interesting_f64(), b in interesting_f64()) {
+            prop_assert_eq!(a.cmp(&b).reverse(), b.cmp(&a));
+            prop_assert_eq!(a.cmp(&b) == Ordering::Equal, a == b);
+            prop_assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+        }
+
+        #[test]
+        fn f64_hash_consistent_with_eq(a in interesting_f64(), b in interesting_f64()) {
+            if a == b {
+                prop_assert_eq!(calculate_hash(&a), calculate_hash(&b));
+            }
+        }
+
+        #[test]
+        fn f64_strategies(a in finite_f64(), b in not_nan_f64()) {
+            prop_assert!(a.0.is_finite());
+            prop_assert!(!b.0.is_nan());
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! `pyo3` support. `F32` and `F64` convert from and to Python floats (Python ints are accepted
+//! as well), so they can be used directly in the signatures of exported functions, including as
+//! keys of `HashMap` arguments. `NAN` and signed zeros survive in both directions.
+
+use pyo3::types::PyAnyMethods;
+use pyo3::{Bound, FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python, ToPyObject};
+
+use crate::{F32, F64};
+
+impl<'py> FromPyObject<'py> for F32 {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        ob.extract().map(F32)
+    }
+}
+
+impl ToPyObject for F32 {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        self.0.to_object(py)
+    }
+}
+
+impl IntoPy<PyObject> for F32 {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.0.into_py(py)
+    }
+}
+
+impl<'py> FromPyObject<'py> for F64 {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        ob.extract().map(F64)
+    }
+}
+
+impl ToPyObject for F64 {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        self.0.to_object(py)
+    }
+}
+
+impl IntoPy<PyObject> for F64 {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.0.into_py(py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pyo3::types::PyAnyMethods;
+    use pyo3::{IntoPy, PyObject, Python, ToPyObject};
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn round_trip() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            for f in [f64::NAN, -0.0, 1.0e300] {
+                let obj = F64(f).to_object(py);
+                let back: F64 = obj.extract(py).unwrap();
+                assert_eq!(back.0.to_bits(), f.to_bits());
+            }
+
+            for f in [f32::NAN, -0.0, 1.0e30] {
+                let obj: PyObject = F32(f).into_py(py);
+                let back: F32 = obj.extract(py).unwrap();
+                assert_eq!(back.0.to_bits(), f.to_bits());
+            }
+        });
+    }
+
+    #[test]
+    fn extract_int_and_collections() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let int = py.eval_bound("3", None, None).unwrap();
+            assert_eq!(int.extract::<F64>().unwrap(), F64(3.0));
+
+            let list = vec![F64(1.5), F64(f64::NAN)].to_object(py);
+            assert_eq!(list.extract::<Vec<F64>>(py).unwrap(), [F64(1.5), F64(f64::NAN)]);
+
+            let dict = py.eval_bound("{0.5: 'a', -0.0: 'b'}", None, None).unwrap();
+            let map: HashMap<F64, String> = dict.extract().unwrap();
+            assert_eq!(map[&F64(0.5)], "a");
+            assert_eq!(map[&F64(0.0)], "b");
+        });
+    }
+}
@@ -0,0 +1,97 @@
+//! `quickcheck` support. Generated values are those of the primitive generators, but a quarter of
+//! them are drawn from a fixed set of special values (`NAN`, signed zeros, infinities, extreme and
+//! subnormal values) which tend to break float-handling code.
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{F32, F64};
+
+const SPECIAL_F32: [f32; 11] = [
+    f32::NAN,
+    -f32::NAN,
+    0.0,
+    -0.0,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::MIN,
+    f32::MAX,
+    f32::MIN_POSITIVE,
+    f32::from_bits(1),           // smallest positive subnormal
+    f32::from_bits(0x8000_0001), // smallest negative subnormal
+];
+
+const SPECIAL_F64: [f64; 11] = [
+    f64::NAN,
+    -f64::NAN,
+    0.0,
+    -0.0,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::MIN,
+    f64::MAX,
+    f64::MIN_POSITIVE,
+    f64::from_bits(1),                     // smallest positive subnormal
+    f64::from_bits(0x8000_0000_0000_0001), // smallest negative subnormal
+];
+
+impl Arbitrary for F32 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        if u8::arbitrary(g) % 4 == 0 {
+            F32(*g.choose(&SPECIAL_F32).unwrap())
+        } else {
+            F32(f32::arbitrary(g))
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(F32))
+    }
+}
+
+impl Arbitrary for F64 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        if u8::arbitrary(g) % 4 == 0 {
+            F64(*g.choose(&SPECIAL_F64).unwrap())
+        } else {
+            F64(f64::arbitrary(g))
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(F64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{quickcheck, Arbitrary, Gen};
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn quickcheck_cmp_reverse() {
+        fn prop32(a: F32, b: F32) -> bool {
+            a.cmp(&b).reverse() == b.cmp(&a)
+        }
+        fn prop64(a: F64, b: F64) -> bool {
+            a.cmp(&b).reverse() == b.cmp(&a)
+        }
+        quickcheck(prop32 as fn(F32, F32) -> bool);
+        quickcheck(prop64 as fn(F64, F64) -> bool);
+    }
+
+    #[test]
+    fn quickcheck_generates_special_values() {
+        let mut g = Gen::new(100);
+        let values: Vec<F64> = (0..1000).map(|_| F64::arbitrary(&mut g)).collect();
+        assert!(values.iter().any(|f| f.0.is_nan()));
+        assert!(values.iter().any(|f| f.0.is_infinite()));
+    }
+
+    #[test]
+    fn quickcheck_shrink_stays_wrapped() {
+        let shrunk: Vec<F32> = F32(100.5).shrink().collect();
+        let expected: Vec<F32> = 100.5f32.shrink().map(F32).collect();
+        assert_eq!(shrunk, expected);
+    }
+}
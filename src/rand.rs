@@ -0,0 +1,152 @@
+//! `rand` support.
+//!
+//! The `Standard` distribution samples the inner float exactly like it does for the primitives.
+//! Uniform range sampling (`rng.gen_range(F64(0.0)..F64(1.0))`) also delegates to the primitive
+//! implementation, but panics if either bound is `NAN`: while `NAN` is the least value in the
+//! order of this crate, there are no values between `NAN` and any other float to sample.
+
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformFloat, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::{F32, F64};
+
+impl Distribution<F32> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F32 {
+        F32(rng.gen())
+    }
+}
+
+impl Distribution<F64> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F64 {
+        F64(rng.gen())
+    }
+}
+
+/// The `UniformSampler` for `F32`.
+#[derive(Debug, Clone, Copy)]
+pub struct UniformF32(UniformFloat<f32>);
+
+impl UniformSampler for UniformF32 {
+    type X = F32;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<F32> + Sized,
+        B2: SampleBorrow<F32> + Sized,
+    {
+        let (low, high) = (low.borrow().0, high.borrow().0);
+        assert!(
+            !low.is_nan() && !high.is_nan(),
+            "can not sample from a range with a NAN bound"
+        );
+        UniformF32(UniformFloat::new(low, high))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<F32> + Sized,
+        B2: SampleBorrow<F32> + Sized,
+    {
+        let (low, high) = (low.borrow().0, high.borrow().0);
+        assert!(
+            !low.is_nan() && !high.is_nan(),
+            "can not sample from a range with a NAN bound"
+        );
+        UniformF32(UniformFloat::new_inclusive(low, high))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F32 {
+        F32(self.0.sample(rng))
+    }
+}
+
+impl SampleUniform for F32 {
+    type Sampler = UniformF32;
+}
+
+/// The `UniformSampler` for `F64`.
+#[derive(Debug, Clone, Copy)]
+pub struct UniformF64(UniformFloat<f64>);
+
+impl UniformSampler for UniformF64 {
+    type X = F64;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<F64> + Sized,
+        B2: SampleBorrow<F64> + Sized,
+    {
+        let (low, high) = (low.borrow().0, high.borrow().0);
+        assert!(
+            !low.is_nan() && !high.is_nan(),
+            "can not sample from a range with a NAN bound"
+        );
+        UniformF64(UniformFloat::new(low, high))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<F64> + Sized,
+        B2: SampleBorrow<F64> + Sized,
+    {
+        let (low, high) = (low.borrow().0, high.borrow().0);
+        assert!(
+            !low.is_nan() && !high.is_nan(),
+            "can not sample from a range with a NAN bound"
+        );
+        UniformF64(UniformFloat::new_inclusive(low, high))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F64 {
+        F64(self.0.sample(rng))
+    }
+}
+
+impl SampleUniform for F64 {
+    type Sampler = UniformF64;
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn rand_standard() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let f: F32 = rng.gen();
+            assert!(F32(0.0) <= f && f < F32(1.0));
+            let f: F64 = rng.gen();
+            assert!(F64(0.0) <= f && f < F64(1.0));
+        }
+    }
+
+    #[test]
+    fn rand_gen_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let f = rng.gen_range(F32(-2.0)..F32(3.0));
+            assert!(F32(-2.0) <= f && f < F32(3.0));
+            let f = rng.gen_range(F64(0.5)..=F64(0.75));
+            assert!(F64(0.5) <= f && f <= F64(0.75));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rand_gen_range_reversed() {
+        let mut rng = StdRng::seed_from_u64(0);
+        rng.gen_range(F64(1.0)..F64(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "NAN bound")]
+    fn rand_gen_range_nan() {
+        let mut rng = StdRng::seed_from_u64(0);
+        rng.gen_range(F32(f32::NAN)..F32(1.0));
+    }
+}
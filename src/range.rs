@@ -0,0 +1,99 @@
+//! Constructors that check that a value lies in a closed range, failing with a `RangeError`
+//! otherwise.
+
+use core::fmt;
+
+use crate::{F32, F64};
+
+/// The error returned by `F32::new_in_range` and `F64::new_in_range`, holding the rejected value
+/// and the bounds it was checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeError<T> {
+    /// The value that was out of range.
+    pub value: T,
+    /// The inclusive lower bound.
+    pub min: T,
+    /// The inclusive upper bound.
+    pub max: T,
+}
+
+impl<T: fmt::Display> fmt::Display for RangeError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} is not in the range [{}, {}]",
+            self.value, self.min, self.max
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + fmt::Display> std::error::Error for RangeError<T> {}
+
+impl F32 {
+    /// Wraps `value` if `min <= value <= max` according to the `Ord` of `F32`, and returns an
+    /// error otherwise. Since `NAN` is less than all other values, it is rejected unless `min`
+    /// is `NAN` as well.
+    pub fn new_in_range(value: f32, min: f32, max: f32) -> Result<F32, RangeError<F32>> {
+        let (value, min, max) = (F32(value), F32(min), F32(max));
+        if min <= value && value <= max {
+            Ok(value)
+        } else {
+            Err(RangeError { value, min, max })
+        }
+    }
+}
+
+impl F64 {
+    /// Wraps `value` if `min <= value <= max` according to the `Ord` of `F64`, and returns an
+    /// error otherwise. Since `NAN` is less than all other values, it is rejected unless `min`
+    /// is `NAN` as well.
+    pub fn new_in_range(value: f64, min: f64, max: f64) -> Result<F64, RangeError<F64>> {
+        let (value, min, max) = (F64(value), F64(min), F64(max));
+        if min <= value && value <= max {
+            Ok(value)
+        } else {
+            Err(RangeError { value, min, max })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeError;
+    use crate::{F32, F64};
+
+    #[test]
+    fn new_in_range() {
+        assert_eq!(F32::new_in_range(0.5, 0.0, 1.0), Ok(F32(0.5)));
+        assert_eq!(F32::new_in_range(1.0, 0.0, 1.0), Ok(F32(1.0)));
+        assert_eq!(F32::new_in_range(-0.0, 0.0, 1.0), Ok(F32(0.0)));
+        assert_eq!(
+            F32::new_in_range(-0.5, 0.0, 1.0),
+            Err(RangeError {
+                value: F32(-0.5),
+                min: F32(0.0),
+                max: F32(1.0),
+            })
+        );
+        assert_eq!(
+            F32::new_in_range(1.5, 0.0, 1.0).unwrap_err().value,
+            F32(1.5)
+        );
+        assert!(F32::new_in_range(f32::NAN, 0.0, 1.0).is_err());
+
+        assert_eq!(F64::new_in_range(0.5, 0.0, 1.0), Ok(F64(0.5)));
+        assert!(F64::new_in_range(-0.5, 0.0, 1.0).is_err());
+        assert!(F64::new_in_range(1.5, 0.0, 1.0).is_err());
+        assert_eq!(
+            F64::new_in_range(f64::NAN, 0.0, 1.0).unwrap_err().value,
+            F64(f64::NAN)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let err = F64::new_in_range(1.5, 0.0, 1.0).unwrap_err();
+        assert_eq!(err.to_string(), "1.5 is not in the range [0, 1]");
+    }
+}
@@ -0,0 +1,215 @@
+//! `rayon` support: parallel sorting of slices of primitives by the order of this crate, and
+//! parallel minimum and maximum reductions.
+//!
+//! Everything is a thin layer over the `Ord` implementations of `F32` and `F64`, so `NAN` is the
+//! least value, and `0.0` and `-0.0` are equal.
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
+
+use crate::{F32, F64};
+
+/// Extension methods for slices of `f32` or `f64`, treating them like slices of `F32` or `F64`.
+pub trait EqFloatParSliceExt {
+    /// The primitive float type of the elements.
+    type Float;
+
+    /// Sorts the slice in parallel like `<[F32]>::par_sort` (or `<[F64]>::par_sort`) would.
+    fn par_sort_total(&mut self);
+
+    /// Sorts the slice in parallel like `<[F32]>::par_sort_unstable` (or
+    /// `<[F64]>::par_sort_unstable`) would.
+    fn par_sort_unstable_total(&mut self);
+
+    /// Returns the least element in parallel, or `None` if the slice is empty.
+    fn total_min(&self) -> Option<Self::Float>;
+
+    /// Returns the greatest element in parallel, or `None` if the slice is empty.
+    fn total_max(&self) -> Option<Self::Float>;
+
+    /// Returns the least and the greatest element in parallel, or `None` if the slice is empty.
+    fn total_minmax(&self) -> Option<(Self::Float, Self::Float)>;
+}
+
+impl EqFloatParSliceExt for [f32] {
+    type Float = f32;
+
+    fn par_sort_total(&mut self) {
+        F32::wrap_slice_mut(self).par_sort();
+    }
+
+    fn par_sort_unstable_total(&mut self) {
+        F32::wrap_slice_mut(self).par_sort_unstable();
+    }
+
+    fn total_min(&self) -> Option<f32> {
+        wrapped_f32s(self).total_min().map(f32::from)
+    }
+
+    fn total_max(&self) -> Option<f32> {
+        wrapped_f32s(self).total_max().map(f32::from)
+    }
+
+    fn total_minmax(&self) -> Option<(f32, f32)> {
+        wrapped_f32s(self)
+            .total_minmax()
+            .map(|(min, max)| (min.0, max.0))
+    }
+}
+
+impl EqFloatParSliceExt for [f64] {
+    type Float = f64;
+
+    fn par_sort_total(&mut self) {
+        F64::wrap_slice_mut(self).par_sort();
+    }
+
+    fn par_sort_unstable_total(&mut self) {
+        F64::wrap_slice_mut(self).par_sort_unstable();
+    }
+
+    fn total_min(&self) -> Option<f64> {
+        wrapped_f64s(self).total_min().map(f64::from)
+    }
+
+    fn total_max(&self) -> Option<f64> {
+        wrapped_f64s(self).total_max().map(f64::from)
+    }
+
+    fn total_minmax(&self) -> Option<(f64, f64)> {
+        wrapped_f64s(self)
+            .total_minmax()
+            .map(|(min, max)| (min.0, max.0))
+    }
+}
+
+fn wrapped_f32s(s: &[f32]) -> impl ParallelIterator<Item = F32> + '_ {
+    F32::wrap_slice(s).into_par_iter().copied()
+}
+
+fn wrapped_f64s(s: &[f64]) -> impl ParallelIterator<Item = F64> + '_ {
+    F64::wrap_slice(s).into_par_iter().copied()
+}
+
+/// Extension methods for parallel iterators over `F32` or `F64`.
+pub trait EqFloatParallelIteratorExt<T>: ParallelIterator<Item = T> {
+    /// Returns the least element, or `None` if the iterator is empty. This is the same as
+    /// `ParallelIterator::min`.
+    fn total_min(self) -> Option<T>;
+
+    /// Returns the greatest element, or `None` if the iterator is empty. This is the same as
+    /// `ParallelIterator::max`.
+    fn total_max(self) -> Option<T>;
+
+    /// Returns the least and the greatest element, or `None` if the iterator is empty.
+    fn total_minmax(self) -> Option<(T, T)>;
+}
+
+impl<I: ParallelIterator<Item = F32>> EqFloatParallelIteratorExt<F32> for I {
+    fn total_min(self) -> Option<F32> {
+        self.min()
+    }
+
+    fn total_max(self) -> Option<F32> {
+        self.max()
+    }
+
+    fn total_minmax(self) -> Option<(F32, F32)> {
+        self.map(|f| (f, f))
+            .reduce_with(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+    }
+}
+
+impl<I: ParallelIterator<Item = F64>> EqFloatParallelIteratorExt<F64> for I {
+    fn total_min(self) -> Option<F64> {
+        self.min()
+    }
+
+    fn total_max(self) -> Option<F64> {
+        self.max()
+    }
+
+    fn total_minmax(self) -> Option<(F64, F64)> {
+        self.map(|f| (f, f))
+            .reduce_with(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+    use super::{EqFloatParSliceExt, EqFloatParallelIteratorExt};
+    use crate::{F32, F64};
+
+    fn random_f64s(len: usize) -> Vec<f64> {
+        crate::tests::random_bits_u64(0x9e37_79b9_7f4a_7c15)
+            .take(len)
+            .enumerate()
+            .map(|(i, state)| match i % 97 {
+                0 => f64::NAN,
+                1 => -0.0,
+                _ => (state >> 11) as f64 - (1u64 << 52) as f64,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn par_sort_matches_sequential() {
+        let mut v = random_f64s(10_000);
+        let mut expected = F64::wrap_vec(v.clone());
+        expected.sort();
+        v.par_sort_total();
+        assert_eq!(F64::wrap_slice(&v), &expected[..]);
+
+        let mut v: Vec<f32> = random_f64s(10_000).into_iter().map(|f| f as f32).collect();
+        let mut expected = F32::wrap_vec(v.clone());
+        expected.sort_unstable();
+        v.par_sort_unstable_total();
+        assert_eq!(F32::wrap_slice(&v), &expected[..]);
+    }
+
+    #[test]
+    fn reductions_match_sequential() {
+        let v = random_f64s(10_000);
+        let wrapped = F64::wrap_slice(&v);
+        let min = wrapped.iter().min().copied();
+        let max = wrapped.iter().max().copied();
+        assert!(min.unwrap().0.is_nan());
+        assert_eq!(v.total_min().map(F64), min);
+        assert_eq!(v.total_max().map(F64), max);
+        assert_eq!(
+            v.total_minmax().map(|(min, max)| (F64(min), F64(max))),
+            min.zip(max)
+        );
+        assert_eq!(
+            wrapped.into_par_iter().copied().total_minmax(),
+            min.zip(max)
+        );
+
+        let v: Vec<f32> = v.into_iter().map(|f| f as f32).collect();
+        let wrapped = F32::wrap_slice(&v);
+        let min = wrapped.iter().min().copied();
+        let max = wrapped.iter().max().copied();
+        assert!(min.unwrap().0.is_nan());
+        assert_eq!(v.total_min().map(F32), min);
+        assert_eq!(v.total_max().map(F32), max);
+        assert_eq!(
+            v.total_minmax().map(|(min, max)| (F32(min), F32(max))),
+            min.zip(max)
+        );
+        assert_eq!(wrapped.into_par_iter().copied().total_max(), max);
+        assert_eq!(
+            wrapped.into_par_iter().copied().total_minmax(),
+            min.zip(max)
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let v: [f64; 0] = [];
+        assert_eq!(v.total_min(), None);
+        assert_eq!(v.total_minmax(), None);
+        assert_eq!(Vec::<F32>::new().into_par_iter().total_minmax(), None);
+    }
+}
@@ -0,0 +1,130 @@
+//! Rounding to decimal places and significant figures.
+
+use crate::{F32, F64};
+
+// Rounds to `places` decimal places (to the left of the decimal point for negative `places`),
+// with ties away from zero. Values that already have no digits at that position, and values for
+// which the scaling factor overflows, are returned unchanged.
+fn round_places(x: f64, places: i32) -> f64 {
+    if !x.is_finite() || x == 0.0 {
+        return x;
+    }
+
+    if places >= 0 {
+        let factor = 10f64.powi(places);
+        let scaled = x * factor;
+        if !scaled.is_finite() || scaled.abs() >= 4_503_599_627_370_496.0 {
+            x
+        } else {
+            scaled.round() / factor
+        }
+    } else {
+        let factor = 10f64.powi(-places);
+        (x / factor).round() * factor
+    }
+}
+
+// The number of decimal places at which rounding leaves `figures` significant figures.
+fn places_for_figures(x: f64, figures: u8) -> i32 {
+    let magnitude = x.abs().log10().floor() as i32;
+    i32::from(figures.max(1)) - 1 - magnitude
+}
+
+impl F32 {
+    /// Rounds to the given number of decimal places, with ties away from zero (so negative values
+    /// round like their absolute value does), e.g. `F32(3.14159).round_dp(2)` is the `f32`
+    /// closest to `3.14`. The computation happens in `f64`, and the result is rounded to the
+    /// nearest `f32`. `NAN` and the infinities are returned unchanged.
+    pub fn round_dp(self, places: u8) -> Self {
+        F32(round_places(f64::from(self.0), i32::from(places)) as f32)
+    }
+
+    /// Rounds to the given number of significant figures (where `0` is treated like `1`), with
+    /// ties away from zero, e.g. `F32(123456.0).round_sig(2)` is `F32(120000.0)`. The
+    /// computation happens in `f64`, and the result is rounded to the nearest `f32`. `NAN`, the
+    /// infinities and the zeros are returned unchanged.
+    pub fn round_sig(self, figures: u8) -> Self {
+        if !self.0.is_finite() || self.0 == 0.0 {
+            return self;
+        }
+        let x = f64::from(self.0);
+        F32(round_places(x, places_for_figures(x, figures)) as f32)
+    }
+}
+
+impl F64 {
+    /// Rounds to the given number of decimal places, with ties away from zero (so negative values
+    /// round like their absolute value does), e.g. `F64(3.14159).round_dp(2)` is the `f64`
+    /// closest to `3.14`. `NAN` and the infinities are returned unchanged.
+    pub fn round_dp(self, places: u8) -> Self {
+        F64(round_places(self.0, i32::from(places)))
+    }
+
+    /// Rounds to the given number of significant figures (where `0` is treated like `1`), with
+    /// ties away from zero, e.g. `F64(123456.0).round_sig(2)` is `F64(120000.0)`.
+    /// `NAN`, the infinities and the zeros are returned unchanged.
+    pub fn round_sig(self, figures: u8) -> Self {
+        if !self.0.is_finite() || self.0 == 0.0 {
+            return self;
+        }
+        let x = self.0;
+        F64(round_places(x, places_for_figures(x, figures)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f32_round_dp() {
+        assert_eq!(F32(1.23456).round_dp(2), F32(1.23));
+        assert_eq!(F32(-1.23456).round_dp(3), F32(-1.235));
+        assert_eq!(F32(2.5).round_dp(0), F32(3.0));
+        assert_eq!(F32(-2.5).round_dp(0), F32(-3.0));
+        assert_eq!(F32(1.005).round_dp(1), F32(1.0));
+        assert_eq!(F32(1e30).round_dp(5), F32(1e30));
+        assert_eq!(F32(0.1).round_dp(255), F32(0.1));
+        assert_eq!(F32(f32::NAN).round_dp(2), F32(f32::NAN));
+        assert_eq!(F32(f32::NEG_INFINITY).round_dp(2), F32(f32::NEG_INFINITY));
+    }
+
+    #[test]
+    fn f32_round_sig() {
+        assert_eq!(F32(123456.0).round_sig(2), F32(120000.0));
+        assert_eq!(F32(-0.00123456).round_sig(3), F32(-0.00123));
+        assert_eq!(F32(9.96).round_sig(2), F32(10.0));
+        assert_eq!(F32(1.23456).round_sig(0), F32(1.0));
+        assert_eq!(F32(1.23456).round_sig(1), F32(1.0));
+        assert_eq!(F32(1e-5).round_sig(3), F32(1e-5));
+        assert!(F32(-0.0).round_sig(3).0.is_sign_negative());
+        assert_eq!(F32(f32::NAN).round_sig(3), F32(f32::NAN));
+        assert_eq!(F32(f32::INFINITY).round_sig(3), F32(f32::INFINITY));
+    }
+
+    #[test]
+    fn f64_round_dp() {
+        assert_eq!(F64(1.23456).round_dp(2), F64(1.23));
+        assert_eq!(F64(-1.23456).round_dp(3), F64(-1.235));
+        assert_eq!(F64(2.5).round_dp(0), F64(3.0));
+        assert_eq!(F64(-2.5).round_dp(0), F64(-3.0));
+        assert_eq!(F64(1.005).round_dp(1), F64(1.0));
+        assert_eq!(F64(1e30).round_dp(5), F64(1e30));
+        assert_eq!(F64(0.1).round_dp(255), F64(0.1));
+        assert_eq!(F64(f64::NAN).round_dp(2), F64(f64::NAN));
+        assert_eq!(F64(f64::NEG_INFINITY).round_dp(2), F64(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn f64_round_sig() {
+        assert_eq!(F64(123456.0).round_sig(2), F64(120000.0));
+        assert_eq!(F64(-0.00123456).round_sig(3), F64(-0.00123));
+        assert_eq!(F64(9.96).round_sig(2), F64(10.0));
+        assert_eq!(F64(1.23456).round_sig(0), F64(1.0));
+        assert_eq!(F64(1.23456).round_sig(1), F64(1.0));
+        assert_eq!(F64(1e-5).round_sig(3), F64(1e-5));
+        assert!(F64(-0.0).round_sig(3).0.is_sign_negative());
+        assert_eq!(F64(f64::NAN).round_sig(3), F64(f64::NAN));
+        assert_eq!(F64(f64::INFINITY).round_sig(3), F64(f64::INFINITY));
+    }
+}
@@ -0,0 +1,34 @@
+//! `rusqlite` support. `F32` and `F64` are bound as `REAL` and read from `REAL` or `INTEGER`
+//! columns, exactly like `f32` and `f64` are. Reading `NULL` fails with the usual
+//! `FromSqlError::InvalidType`, so use `Option<F32>` or `Option<F64>` for nullable columns.
+//!
+//! Note that SQLite stores `NAN` as `NULL`.
+
+use rusqlite::types::{FromSql, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use rusqlite::Result;
+
+use crate::{F32, F64};
+
+impl ToSql for F32 {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        self.0.to_sql()
+    }
+}
+
+impl FromSql for F32 {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        f32::column_result(value).map(F32)
+    }
+}
+
+impl ToSql for F64 {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        self.0.to_sql()
+    }
+}
+
+impl FromSql for F64 {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        f64::column_result(value).map(F64)
+    }
+}
@@ -0,0 +1,98 @@
+//! Shortest round-trip formatting via `ryu`.
+
+use core::fmt;
+
+use crate::{F32, F64};
+
+impl F32 {
+    /// Writes the shortest decimal representation that parses back to exactly this value (up to
+    /// the sign of `NAN`), using `ryu`. `NAN` and the infinities are written as `NaN`, `inf` and
+    /// `-inf`, like `Display` does.
+    pub fn format_shortest<W: fmt::Write>(self, w: &mut W) -> fmt::Result {
+        if self.0.is_nan() {
+            w.write_str("NaN")
+        } else if self.0 == f32::INFINITY {
+            w.write_str("inf")
+        } else if self.0 == f32::NEG_INFINITY {
+            w.write_str("-inf")
+        } else {
+            w.write_str(ryu::Buffer::new().format_finite(self.0))
+        }
+    }
+}
+
+impl F64 {
+    /// Writes the shortest decimal representation that parses back to exactly this value (up to
+    /// the sign of `NAN`), using `ryu`. `NAN` and the infinities are written as `NaN`, `inf` and
+    /// `-inf`, like `Display` does.
+    pub fn format_shortest<W: fmt::Write>(self, w: &mut W) -> fmt::Result {
+        if self.0.is_nan() {
+            w.write_str("NaN")
+        } else if self.0 == f64::INFINITY {
+            w.write_str("inf")
+        } else if self.0 == f64::NEG_INFINITY {
+            w.write_str("-inf")
+        } else {
+            w.write_str(ryu::Buffer::new().format_finite(self.0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shortest<T: Copy>(x: T, format: fn(T, &mut String) -> fmt::Result) -> String {
+        let mut s = String::new();
+        format(x, &mut s).unwrap();
+        s
+    }
+
+    fn random_bits() -> impl Iterator<Item = u64> {
+        crate::tests::random_bits_u64(0x9e37_79b9_7f4a_7c15).take(100_000)
+    }
+
+    #[test]
+    fn f32_special() {
+        assert_eq!(shortest(F32(f32::NAN), F32::format_shortest), "NaN");
+        assert_eq!(shortest(F32(f32::INFINITY), F32::format_shortest), "inf");
+        assert_eq!(
+            shortest(F32(f32::NEG_INFINITY), F32::format_shortest),
+            "-inf"
+        );
+        assert_eq!(shortest(F32(-0.0), F32::format_shortest), "-0.0");
+        assert_eq!(shortest(F32(0.1), F32::format_shortest), "0.1");
+    }
+
+    #[test]
+    fn f32_round_trip() {
+        for bits in random_bits() {
+            let x = F32(f32::from_bits(bits as u32));
+            let parsed: F32 = shortest(x, F32::format_shortest).parse().unwrap();
+            assert_eq!(parsed, x);
+            assert!(x.0.is_nan() || parsed.0.to_bits() == x.0.to_bits());
+        }
+    }
+
+    #[test]
+    fn f64_special() {
+        assert_eq!(shortest(F64(f64::NAN), F64::format_shortest), "NaN");
+        assert_eq!(shortest(F64(f64::INFINITY), F64::format_shortest), "inf");
+        assert_eq!(
+            shortest(F64(f64::NEG_INFINITY), F64::format_shortest),
+            "-inf"
+        );
+        assert_eq!(shortest(F64(-0.0), F64::format_shortest), "-0.0");
+        assert_eq!(shortest(F64(0.1), F64::format_shortest), "0.1");
+    }
+
+    #[test]
+    fn f64_round_trip() {
+        for bits in random_bits() {
+            let x = F64(f64::from_bits(bits));
+            let parsed: F64 = shortest(x, F64::format_shortest).parse().unwrap();
+            assert_eq!(parsed, x);
+            assert!(x.0.is_nan() || parsed.0.to_bits() == x.0.to_bits());
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Binary search on sorted slices of `F64`.
+//!
+//! The slices must be sorted by the `Ord` of `F64`, so all `NAN`s come first, followed by
+//! negative infinity and then the other values in ascending order. Searching for `NAN` finds
+//! that leading run of `NAN`s.
+
+use crate::F64;
+
+/// Searches for `target` like `<[F64]>::binary_search` does: returns `Ok` with the index of an
+/// element equal to `target`, or `Err` with the index at which `target` could be inserted while
+/// keeping the slice sorted.
+pub fn binary_search_float(slice: &[F64], target: F64) -> Result<usize, usize> {
+    slice.binary_search(&target)
+}
+
+/// Returns the index of the first element for which `pred` is false, like
+/// `<[F64]>::partition_point` does. The slice must be partitioned by `pred`, i.e. all elements
+/// for which it holds must come first.
+pub fn partition_point_float<P: FnMut(F64) -> bool>(slice: &[F64], mut pred: P) -> usize {
+    slice.partition_point(|x| pred(*x))
+}
+
+/// Returns the index of the first element that is not less than `target`, i.e. the first
+/// element equal to `target` if there is one. For a `NAN` target this is always `0`.
+pub fn lower_bound(slice: &[F64], target: F64) -> usize {
+    partition_point_float(slice, |x| x < target)
+}
+
+/// Returns the index of the first element that is greater than `target`, i.e. one past the
+/// last element equal to `target` if there is one. For a `NAN` target this is the number of
+/// `NAN`s in the slice.
+pub fn upper_bound(slice: &[F64], target: F64) -> usize {
+    partition_point_float(slice, |x| x <= target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SORTED: [F64; 8] = [
+        F64(f64::NAN),
+        F64(f64::NAN),
+        F64(f64::NEG_INFINITY),
+        F64(-1.0),
+        F64(0.0),
+        F64(0.0),
+        F64(2.0),
+        F64(f64::INFINITY),
+    ];
+
+    #[test]
+    fn binary_search() {
+        let nan = binary_search_float(&SORTED, F64(-f64::NAN)).unwrap();
+        assert!(nan < 2);
+        assert_eq!(binary_search_float(&SORTED, F64(-1.0)), Ok(3));
+        assert!(matches!(
+            binary_search_float(&SORTED, F64(-0.0)),
+            Ok(4) | Ok(5)
+        ));
+        assert_eq!(binary_search_float(&SORTED, F64(1.0)), Err(6));
+        assert_eq!(binary_search_float(&SORTED, F64(f64::INFINITY)), Ok(7));
+        assert_eq!(binary_search_float(&SORTED[2..], F64(f64::NAN)), Err(0));
+        assert_eq!(binary_search_float(&[], F64(1.0)), Err(0));
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(lower_bound(&SORTED, F64(f64::NAN)), 0);
+        assert_eq!(upper_bound(&SORTED, F64(f64::NAN)), 2);
+        assert_eq!(lower_bound(&SORTED, F64(-0.0)), 4);
+        assert_eq!(upper_bound(&SORTED, F64(0.0)), 6);
+        assert_eq!(lower_bound(&SORTED, F64(1.0)), 6);
+        assert_eq!(upper_bound(&SORTED, F64(1.0)), 6);
+        assert_eq!(upper_bound(&SORTED, F64(f64::INFINITY)), 8);
+        assert_eq!(partition_point_float(&SORTED, |x| x.0.is_nan()), 2);
+    }
+}
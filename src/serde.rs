@@ -0,0 +1,73 @@
+//! `serde` support.
+//!
+//! Human-readable formats (JSON, TOML, ...) see a plain float, exactly like the primitive. Binary
+//! formats are free to re-encode floats (CBOR for example shrinks every `NAN` to a canonical half
+//! precision `NAN`), so for serializers that are not human-readable, the raw bits of the float
+//! are serialized as an unsigned integer instead. This preserves every bit pattern, including
+//! `NAN` payloads and signs.
+//!
+//! Fields that must not be `NAN` can be deserialized with `deserialize_not_nan`.
+
+use serde::de::{self, Unexpected};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{F32, F64};
+
+impl Serialize for F32 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_f32(self.0)
+        } else {
+            serializer.serialize_u32(self.0.to_bits())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for F32 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            f32::deserialize(deserializer).map(F32)
+        } else {
+            u32::deserialize(deserializer).map(f32::from_bits).map(F32)
+        }
+    }
+}
+
+impl Serialize for F64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_f64(self.0)
+        } else {
+            serializer.serialize_u64(self.0.to_bits())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for F64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            f64::deserialize(deserializer).map(F64)
+        } else {
+            u64::deserialize(deserializer).map(f64::from_bits).map(F64)
+        }
+    }
+}
+
+/// Deserializes a float like its `Deserialize` implementation does, but fails with an
+/// `invalid_value` error instead of returning a `NAN`. Works for `F32`, `F64`, `f32` and `f64`,
+/// use it as `#[serde(deserialize_with = "eq_float::serde::deserialize_not_nan")]`.
+pub fn deserialize_not_nan<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Copy + Into<F64>,
+{
+    let value = T::deserialize(deserializer)?;
+    if value.into().0.is_nan() {
+        Err(de::Error::invalid_value(
+            Unexpected::Float(f64::NAN),
+            &"a float that is not NaN",
+        ))
+    } else {
+        Ok(value)
+    }
+}
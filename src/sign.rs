@@ -0,0 +1,100 @@
+//! Inspecting the sign of floats, including the signs of zeros and `NAN`s which comparisons can
+//! not tell apart: `sign_bit` and `classify_sign`, which returns a `SignClass`.
+
+use crate::{F32, F64};
+
+/// The sign of a float together with whether it is zero, nonzero or `NAN`, as returned by
+/// `F32::classify_sign` and `F64::classify_sign`. Unlike `signum`, this tells apart both zeros
+/// and both signs of `NAN`. Infinities count as `Negative` and `Positive` respectively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignClass {
+    /// A `NAN` with the sign bit set.
+    NegNan,
+    /// A value less than zero.
+    Negative,
+    /// `-0.0`.
+    NegZero,
+    /// `0.0`.
+    PosZero,
+    /// A value greater than zero.
+    Positive,
+    /// A `NAN` with the sign bit unset.
+    PosNan,
+}
+
+impl F32 {
+    /// Returns the raw sign bit of the inner float, so this is `true` for `-0.0` and for `NAN`s
+    /// with the sign bit set.
+    pub fn sign_bit(self) -> bool {
+        self.0.is_sign_negative()
+    }
+
+    /// Classifies the inner float by its sign, telling apart both zeros and both signs of `NAN`.
+    pub fn classify_sign(self) -> SignClass {
+        match (self.0.is_nan(), self.0 == 0.0, self.sign_bit()) {
+            (true, _, true) => SignClass::NegNan,
+            (true, _, false) => SignClass::PosNan,
+            (false, true, true) => SignClass::NegZero,
+            (false, true, false) => SignClass::PosZero,
+            (false, false, true) => SignClass::Negative,
+            (false, false, false) => SignClass::Positive,
+        }
+    }
+}
+
+impl F64 {
+    /// Returns the raw sign bit of the inner float, so this is `true` for `-0.0` and for `NAN`s
+    /// with the sign bit set.
+    pub fn sign_bit(self) -> bool {
+        self.0.is_sign_negative()
+    }
+
+    /// Classifies the inner float by its sign, telling apart both zeros and both signs of `NAN`.
+    pub fn classify_sign(self) -> SignClass {
+        match (self.0.is_nan(), self.0 == 0.0, self.sign_bit()) {
+            (true, _, true) => SignClass::NegNan,
+            (true, _, false) => SignClass::PosNan,
+            (false, true, true) => SignClass::NegZero,
+            (false, true, false) => SignClass::PosZero,
+            (false, false, true) => SignClass::Negative,
+            (false, false, false) => SignClass::Positive,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignClass;
+    use crate::{F32, F64};
+
+    #[test]
+    fn sign_bit() {
+        assert!(F32(-0.0).sign_bit());
+        assert!(!F32(0.0).sign_bit());
+        assert!(F32(-f32::NAN).sign_bit());
+        assert!(!F32(1.0).sign_bit());
+
+        assert!(F64(-0.0).sign_bit());
+        assert!(!F64(0.0).sign_bit());
+        assert!(F64(-f64::NAN).sign_bit());
+        assert!(F64(f64::NEG_INFINITY).sign_bit());
+    }
+
+    #[test]
+    fn classify_sign() {
+        assert_eq!(F32(-f32::NAN).classify_sign(), SignClass::NegNan);
+        assert_eq!(F32(f32::NEG_INFINITY).classify_sign(), SignClass::Negative);
+        assert_eq!(F32(-1.5).classify_sign(), SignClass::Negative);
+        assert_eq!(F32(-0.0).classify_sign(), SignClass::NegZero);
+        assert_eq!(F32(0.0).classify_sign(), SignClass::PosZero);
+        assert_eq!(F32(f32::from_bits(1)).classify_sign(), SignClass::Positive);
+        assert_eq!(F32(f32::NAN).classify_sign(), SignClass::PosNan);
+
+        assert_eq!(F64(-f64::NAN).classify_sign(), SignClass::NegNan);
+        assert_eq!(F64(-1.5).classify_sign(), SignClass::Negative);
+        assert_eq!(F64(-0.0).classify_sign(), SignClass::NegZero);
+        assert_eq!(F64(0.0).classify_sign(), SignClass::PosZero);
+        assert_eq!(F64(f64::INFINITY).classify_sign(), SignClass::Positive);
+        assert_eq!(F64(f64::NAN).classify_sign(), SignClass::PosNan);
+    }
+}
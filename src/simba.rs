@@ -0,0 +1,178 @@
+//! `simba` support, so that `F32` and `F64` can be used as the scalars of `nalgebra` vectors and
+//! matrices (and those can then be compared and hashed as a whole). Everything delegates to the
+//! implementations for the inner float.
+//!
+//! `RealField` and `ComplexField` are not implemented: `RealField` requires `RelativeEq` and
+//! `UlpsEq` with `Self` as the `Epsilon` type, but the `approx` implementations of this crate use
+//! the primitive as the `Epsilon` type. `Field`, `SimdValue` and `SubsetOf` suffice for building
+//! and multiplying matrices, but not for decompositions or norms.
+
+use simba::scalar::{Field, SubsetOf};
+use simba::simd::SimdValue;
+
+use crate::{F32, F64};
+
+macro_rules! impl_simba {
+    ($t:ident, $float:ident) => {
+        impl SimdValue for $t {
+            const LANES: usize = 1;
+            type Element = $t;
+            type SimdBool = bool;
+
+            #[inline]
+            fn splat(val: Self::Element) -> Self {
+                val
+            }
+
+            #[inline]
+            fn extract(&self, _: usize) -> Self::Element {
+                *self
+            }
+
+            #[inline]
+            unsafe fn extract_unchecked(&self, _: usize) -> Self::Element {
+                *self
+            }
+
+            #[inline]
+            fn replace(&mut self, _: usize, val: Self::Element) {
+                *self = val
+            }
+
+            #[inline]
+            unsafe fn replace_unchecked(&mut self, _: usize, val: Self::Element) {
+                *self = val
+            }
+
+            #[inline]
+            fn select(self, cond: Self::SimdBool, other: Self) -> Self {
+                if cond {
+                    self
+                } else {
+                    other
+                }
+            }
+        }
+
+        impl Field for $t {}
+
+        impl SubsetOf<$t> for $t {
+            #[inline]
+            fn to_superset(&self) -> $t {
+                *self
+            }
+
+            #[inline]
+            fn from_superset_unchecked(element: &$t) -> Self {
+                *element
+            }
+
+            #[inline]
+            fn is_in_subset(_: &$t) -> bool {
+                true
+            }
+        }
+
+        impl SubsetOf<$t> for $float {
+            #[inline]
+            fn to_superset(&self) -> $t {
+                $t(*self)
+            }
+
+            #[inline]
+            fn from_superset_unchecked(element: &$t) -> Self {
+                element.0
+            }
+
+            #[inline]
+            fn is_in_subset(_: &$t) -> bool {
+                true
+            }
+        }
+    };
+}
+
+impl_simba!(F32, f32);
+impl_simba!(F64, f64);
+
+/// Widening is exact, an `F64` is in the subset if narrowing it is exact as well. Every `NAN` is
+/// in the subset.
+impl SubsetOf<F64> for F32 {
+    #[inline]
+    fn to_superset(&self) -> F64 {
+        F64(f64::from(self.0))
+    }
+
+    #[inline]
+    fn from_superset_unchecked(element: &F64) -> Self {
+        F32(element.0 as f32)
+    }
+
+    #[inline]
+    fn is_in_subset(element: &F64) -> bool {
+        element.0.is_nan() || f64::from(element.0 as f32) == element.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use nalgebra::{DVector, Matrix3};
+    use simba::scalar::SupersetOf;
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn matrix_as_key() {
+        let a = Matrix3::new(
+            F64(1.0),
+            F64(2.0),
+            F64(0.0),
+            F64(-1.0),
+            F64(0.5),
+            F64(3.0),
+            F64(0.0),
+            F64(0.0),
+            F64(1.0),
+        );
+        assert_eq!(a * Matrix3::identity(), a);
+
+        let mut map = HashMap::new();
+        map.insert(a * a, "square");
+        let square = Matrix3::new(
+            F64(-1.0),
+            F64(3.0),
+            F64(6.0),
+            F64(-1.5),
+            F64(-1.75),
+            F64(4.5),
+            F64(0.0),
+            F64(0.0),
+            F64(1.0),
+        );
+        assert_eq!(map.get(&square), Some(&"square"));
+
+        let v = DVector::from_vec(vec![F64(f64::NAN), F64(0.0)]);
+        let mut vectors = HashMap::new();
+        vectors.insert(v, 1);
+        let w = DVector::from_vec(vec![F64(-f64::NAN), F64(-0.0)]);
+        assert_eq!(vectors.get(&w), Some(&1));
+    }
+
+    #[test]
+    fn subsets() {
+        assert_eq!(F64::from_subset(&1.5f64), F64(1.5));
+        assert_eq!(F32::from_subset(&-0.0f32), F32(0.0));
+        assert_eq!(F64::from_subset(&F32(0.5)), F64(0.5));
+        assert_eq!(<F64 as SupersetOf<F32>>::to_subset(&F64(0.1)), None);
+        assert_eq!(
+            <F64 as SupersetOf<F32>>::to_subset(&F64(0.25)),
+            Some(F32(0.25))
+        );
+        assert_eq!(
+            <F64 as SupersetOf<F32>>::to_subset(&F64(f64::NAN)),
+            Some(F32(f32::NAN))
+        );
+    }
+}
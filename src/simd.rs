@@ -0,0 +1,296 @@
+//! Reductions over primitive slices that compile to SIMD code.
+//!
+//! SIMD min and max instructions have their own `NAN` semantics (returning whichever operand
+//! comes second, or the non-`NAN` one), so the floats are never compared directly. Instead, every
+//! value is mapped to its order-preserving integer key, and the keys are reduced with integer min
+//! and max in a fixed number of independent lanes. The mapping is branch-free, so the loops
+//! vectorize on every target with integer SIMD support, and fall back to plain scalar code
+//! elsewhere; the results are the same either way.
+
+use crate::{ordered_key_f32, ordered_key_f64, F32, F64};
+
+/// The number of independent accumulators the reductions use.
+const LANES: usize = 16;
+
+/// Returns the least value of the slice according to `Ord` on `F32` (so `NAN` if the slice
+/// contains one), or `None` if the slice is empty.
+///
+/// The result equals `s.iter().map(|&x| F32(x)).min()`. Zeros are returned as `0.0` and `NAN`s
+/// as `f32::NAN`, whatever their bits in the slice.
+pub fn simd_total_min_f32(s: &[f32]) -> Option<F32> {
+    if s.is_empty() {
+        return None;
+    }
+    let mut acc = [u32::MAX; LANES];
+    let chunks = s.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for (a, x) in acc.iter_mut().zip(chunk) {
+            *a = (*a).min(ordered_key_f32(x.to_bits()));
+        }
+    }
+    let key = rest
+        .iter()
+        .map(|x| ordered_key_f32(x.to_bits()))
+        .chain(acc.iter().copied())
+        .min()?;
+    Some(F32::from_ordered_bits(key))
+}
+
+/// Returns the greatest value of the slice according to `Ord` on `F32` (so `NAN` only if all
+/// values are `NAN`), or `None` if the slice is empty.
+///
+/// The result equals `s.iter().map(|&x| F32(x)).max()`. Zeros are returned as `0.0` and `NAN`s
+/// as `f32::NAN`, whatever their bits in the slice.
+pub fn simd_total_max_f32(s: &[f32]) -> Option<F32> {
+    if s.is_empty() {
+        return None;
+    }
+    let mut acc = [0; LANES];
+    let chunks = s.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for (a, x) in acc.iter_mut().zip(chunk) {
+            *a = (*a).max(ordered_key_f32(x.to_bits()));
+        }
+    }
+    let key = rest
+        .iter()
+        .map(|x| ordered_key_f32(x.to_bits()))
+        .chain(acc.iter().copied())
+        .max()?;
+    Some(F32::from_ordered_bits(key))
+}
+
+/// Returns the sum of the slice, which is `NAN` if any value is `NAN`. The sum of an empty slice
+/// is `0.0`.
+///
+/// Unlike the min and max reductions, this is not identical to its scalar counterpart: the
+/// additions are reassociated so that they vectorize. The value at index `i` of the leading
+/// multiple of 16 values is added to the `i % 16`-th of 16 partial sums, the partial sums are
+/// added in order, and then the remaining values are added one after the other. Since float
+/// addition is not associative, the result can differ from `iter().sum()` in the last bits
+/// whenever an intermediate sum is rounded. It is the same on every target, though.
+pub fn simd_sum_f32(s: &[f32]) -> F32 {
+    let mut acc = [0.0; LANES];
+    let chunks = s.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for (a, x) in acc.iter_mut().zip(chunk) {
+            *a += *x;
+        }
+    }
+    F32(acc.iter().chain(rest).fold(0.0, |sum, x| sum + x))
+}
+
+/// Returns the least value of the slice according to `Ord` on `F64` (so `NAN` if the slice
+/// contains one), or `None` if the slice is empty.
+///
+/// The result equals `s.iter().map(|&x| F64(x)).min()`. Zeros are returned as `0.0` and `NAN`s
+/// as `f64::NAN`, whatever their bits in the slice.
+pub fn simd_total_min_f64(s: &[f64]) -> Option<F64> {
+    if s.is_empty() {
+        return None;
+    }
+    let mut acc = [u64::MAX; LANES];
+    let chunks = s.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for (a, x) in acc.iter_mut().zip(chunk) {
+            *a = (*a).min(ordered_key_f64(x.to_bits()));
+        }
+    }
+    let key = rest
+        .iter()
+        .map(|x| ordered_key_f64(x.to_bits()))
+        .chain(acc.iter().copied())
+        .min()?;
+    Some(F64::from_ordered_bits(key))
+}
+
+/// Returns the greatest value of the slice according to `Ord` on `F64` (so `NAN` only if all
+/// values are `NAN`), or `None` if the slice is empty.
+///
+/// The result equals `s.iter().map(|&x| F64(x)).max()`. Zeros are returned as `0.0` and `NAN`s
+/// as `f64::NAN`, whatever their bits in the slice.
+pub fn simd_total_max_f64(s: &[f64]) -> Option<F64> {
+    if s.is_empty() {
+        return None;
+    }
+    let mut acc = [0; LANES];
+    let chunks = s.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for (a, x) in acc.iter_mut().zip(chunk) {
+            *a = (*a).max(ordered_key_f64(x.to_bits()));
+        }
+    }
+    let key = rest
+        .iter()
+        .map(|x| ordered_key_f64(x.to_bits()))
+        .chain(acc.iter().copied())
+        .max()?;
+    Some(F64::from_ordered_bits(key))
+}
+
+/// Returns the sum of the slice, which is `NAN` if any value is `NAN`. The sum of an empty slice
+/// is `0.0`.
+///
+/// Unlike the min and max reductions, this is not identical to its scalar counterpart: the
+/// additions are reassociated so that they vectorize. The value at index `i` of the leading
+/// multiple of 16 values is added to the `i % 16`-th of 16 partial sums, the partial sums are
+/// added in order, and then the remaining values are added one after the other. Since float
+/// addition is not associative, the result can differ from `iter().sum()` in the last bits
+/// whenever an intermediate sum is rounded. It is the same on every target, though.
+pub fn simd_sum_f64(s: &[f64]) -> F64 {
+    let mut acc = [0.0; LANES];
+    let chunks = s.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for (a, x) in acc.iter_mut().zip(chunk) {
+            *a += *x;
+        }
+    }
+    F64(acc.iter().chain(rest).fold(0.0, |sum, x| sum + x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::random_bits_u64;
+
+    /// Pseudo-random floats with `NAN`s of either sign, zeros of either sign, infinities and
+    /// subnormals mixed in at a boosted rate.
+    fn random_f32s(rng: &mut impl Iterator<Item = u64>, len: usize) -> Vec<f32> {
+        const SPECIAL: [f32; 8] = [
+            f32::NAN,
+            -f32::NAN,
+            0.0,
+            -0.0,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::MIN_POSITIVE,
+            -1.0e-45,
+        ];
+        rng.take(len)
+            .map(|r| match r % 16 {
+                0..=7 => SPECIAL[(r >> 8) as usize % SPECIAL.len()],
+                _ => f32::from_bits((r >> 32) as u32),
+            })
+            .collect()
+    }
+
+    fn random_f64s(rng: &mut impl Iterator<Item = u64>, len: usize) -> Vec<f64> {
+        const SPECIAL: [f64; 8] = [
+            f64::NAN,
+            -f64::NAN,
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::MIN_POSITIVE,
+            -5.0e-324,
+        ];
+        rng.take(len)
+            .map(|r| match r % 16 {
+                0..=7 => SPECIAL[(r >> 8) as usize % SPECIAL.len()],
+                _ => f64::from_bits(r.rotate_left(17)),
+            })
+            .collect()
+    }
+
+    /// Pseudo-random small integers (whose sums are exact, in any order) with `NAN`s, infinities
+    /// and zeros of either sign mixed in.
+    fn random_summands(rng: &mut impl Iterator<Item = u64>, len: usize) -> Vec<f64> {
+        const SPECIAL: [f64; 6] = [
+            f64::NAN,
+            -f64::NAN,
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ];
+        rng.take(len)
+            .map(|r| match r % 64 {
+                0 => SPECIAL[(r >> 8) as usize % SPECIAL.len()],
+                _ => ((r >> 16) % 2001) as f64 - 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(simd_total_min_f32(&[]), None);
+        assert_eq!(simd_total_max_f32(&[]), None);
+        assert_eq!(simd_sum_f32(&[]).0.to_bits(), 0);
+        assert_eq!(simd_total_min_f64(&[]), None);
+        assert_eq!(simd_total_max_f64(&[]), None);
+        assert_eq!(simd_sum_f64(&[]).0.to_bits(), 0);
+    }
+
+    #[test]
+    fn sum_reassociates() {
+        // Sequentially, each `1.0` is lost to rounding. In the partial sums, only the two added to
+        // `1.0e16` are lost, the other 30 survive in pairs.
+        let mut s = [1.0f64; 32];
+        s[0] = 1.0e16;
+        assert_eq!(s.iter().sum::<f64>(), 1.0e16);
+        assert_eq!(simd_sum_f64(&s), F64(1.0e16 + 30.0));
+        assert_eq!(
+            simd_sum_f64(&[f64::INFINITY, 1.0, f64::NEG_INFINITY]),
+            F64(f64::NAN)
+        );
+    }
+
+    #[test]
+    fn nan_sorts_lowest() {
+        let mut s = [1.0f32; 40];
+        s[33] = -f32::NAN;
+        assert!(simd_total_min_f32(&s).unwrap().0.is_nan());
+        assert_eq!(simd_total_max_f32(&s), Some(F32(1.0)));
+        assert!(simd_sum_f32(&s).0.is_nan());
+        assert!(simd_total_max_f32(&[f32::NAN; 20]).unwrap().0.is_nan());
+
+        let mut s = [1.0f64; 40];
+        s[3] = f64::NAN;
+        assert!(simd_total_min_f64(&s).unwrap().0.is_nan());
+        assert_eq!(simd_total_max_f64(&s), Some(F64(1.0)));
+        assert!(simd_sum_f64(&s).0.is_nan());
+        assert!(simd_total_max_f64(&[f64::NAN; 20]).unwrap().0.is_nan());
+    }
+
+    #[test]
+    fn f32_differential() {
+        let mut rng = random_bits_u64(0x9e37_79b9_7f4a_7c15);
+        for len in 0..200 {
+            for _ in 0..8 {
+                let s = random_f32s(&mut rng, len);
+                let wrapped = s.iter().map(|&x| F32(x));
+                assert_eq!(simd_total_min_f32(&s), wrapped.clone().min());
+                assert_eq!(simd_total_max_f32(&s), wrapped.max());
+
+                let s: Vec<f32> = random_summands(&mut rng, len)
+                    .into_iter()
+                    .map(|x| x as f32)
+                    .collect();
+                assert_eq!(simd_sum_f32(&s), F32(s.iter().sum()));
+            }
+        }
+    }
+
+    #[test]
+    fn f64_differential() {
+        let mut rng = random_bits_u64(0x2545_f491_4f6c_dd1d);
+        for len in 0..200 {
+            for _ in 0..8 {
+                let s = random_f64s(&mut rng, len);
+                let wrapped = s.iter().map(|&x| F64(x));
+                assert_eq!(simd_total_min_f64(&s), wrapped.clone().min());
+                assert_eq!(simd_total_max_f64(&s), wrapped.max());
+
+                let s = random_summands(&mut rng, len);
+                assert_eq!(simd_sum_f64(&s), F64(s.iter().sum()));
+            }
+        }
+    }
+}
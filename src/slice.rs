@@ -0,0 +1,164 @@
+//! Conversions between slices (and vectors, with the `alloc` feature) of primitives and of
+//! wrappers, without copying. These are sound since the wrappers are `#[repr(transparent)]`.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::mem::ManuallyDrop;
+
+use crate::{F32, F64};
+
+impl F32 {
+    /// Views a slice of `f32` as a slice of `F32`.
+    pub fn wrap_slice(s: &[f32]) -> &[F32] {
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`.
+        unsafe { &*(s as *const [f32] as *const [F32]) }
+    }
+
+    /// Views a mutable slice of `f32` as a mutable slice of `F32`.
+    pub fn wrap_slice_mut(s: &mut [f32]) -> &mut [F32] {
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`.
+        unsafe { &mut *(s as *mut [f32] as *mut [F32]) }
+    }
+
+    /// Views a slice of `F32` as a slice of `f32`.
+    pub fn unwrap_slice(s: &[F32]) -> &[f32] {
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`.
+        unsafe { &*(s as *const [F32] as *const [f32]) }
+    }
+
+    /// Views a mutable slice of `F32` as a mutable slice of `f32`.
+    pub fn unwrap_slice_mut(s: &mut [F32]) -> &mut [f32] {
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`.
+        unsafe { &mut *(s as *mut [F32] as *mut [f32]) }
+    }
+
+    /// Converts a vector of `f32` into a vector of `F32`, reusing the allocation.
+    #[cfg(feature = "alloc")]
+    pub fn wrap_vec(v: Vec<f32>) -> Vec<F32> {
+        let mut v = ManuallyDrop::new(v);
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`, and `v` is never
+        // used (or dropped) again.
+        unsafe { Vec::from_raw_parts(v.as_mut_ptr() as *mut F32, v.len(), v.capacity()) }
+    }
+
+    /// Converts a vector of `F32` into a vector of `f32`, reusing the allocation.
+    #[cfg(feature = "alloc")]
+    pub fn unwrap_vec(v: Vec<F32>) -> Vec<f32> {
+        let mut v = ManuallyDrop::new(v);
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`, and `v` is never
+        // used (or dropped) again.
+        unsafe { Vec::from_raw_parts(v.as_mut_ptr() as *mut f32, v.len(), v.capacity()) }
+    }
+}
+
+impl F64 {
+    /// Views a slice of `f64` as a slice of `F64`.
+    pub fn wrap_slice(s: &[f64]) -> &[F64] {
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`.
+        unsafe { &*(s as *const [f64] as *const [F64]) }
+    }
+
+    /// Views a mutable slice of `f64` as a mutable slice of `F64`.
+    pub fn wrap_slice_mut(s: &mut [f64]) -> &mut [F64] {
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`.
+        unsafe { &mut *(s as *mut [f64] as *mut [F64]) }
+    }
+
+    /// Views a slice of `F64` as a slice of `f64`.
+    pub fn unwrap_slice(s: &[F64]) -> &[f64] {
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`.
+        unsafe { &*(s as *const [F64] as *const [f64]) }
+    }
+
+    /// Views a mutable slice of `F64` as a mutable slice of `f64`.
+    pub fn unwrap_slice_mut(s: &mut [F64]) -> &mut [f64] {
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`.
+        unsafe { &mut *(s as *mut [F64] as *mut [f64]) }
+    }
+
+    /// Converts a vector of `f64` into a vector of `F64`, reusing the allocation.
+    #[cfg(feature = "alloc")]
+    pub fn wrap_vec(v: Vec<f64>) -> Vec<F64> {
+        let mut v = ManuallyDrop::new(v);
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`, and `v` is never
+        // used (or dropped) again.
+        unsafe { Vec::from_raw_parts(v.as_mut_ptr() as *mut F64, v.len(), v.capacity()) }
+    }
+
+    /// Converts a vector of `F64` into a vector of `f64`, reusing the allocation.
+    #[cfg(feature = "alloc")]
+    pub fn unwrap_vec(v: Vec<F64>) -> Vec<f64> {
+        let mut v = ManuallyDrop::new(v);
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`, and `v` is never
+        // used (or dropped) again.
+        unsafe { Vec::from_raw_parts(v.as_mut_ptr() as *mut f64, v.len(), v.capacity()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn f32_wrap_slice() {
+        let raw = [1.5, f32::NAN, -0.0, 0.0];
+        let wrapped = F32::wrap_slice(&raw);
+        assert_eq!(wrapped.len(), raw.len());
+        for (w, r) in wrapped.iter().zip(raw.iter()) {
+            assert_eq!(*w, F32(*r));
+        }
+        assert_eq!(wrapped.iter().collect::<HashSet<_>>().len(), 3);
+        assert_eq!(F32::unwrap_slice(wrapped).as_ptr(), raw.as_ptr());
+
+        let mut raw = raw;
+        F32::wrap_slice_mut(&mut raw).sort();
+        assert!(raw[0].is_nan());
+        F32::unwrap_slice_mut(F32::wrap_slice_mut(&mut raw))[0] = 2.0;
+        assert_eq!(raw[0], 2.0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn f32_wrap_vec() {
+        let raw = vec![1.5, f32::NAN, -0.0];
+        let ptr = raw.as_ptr();
+        let wrapped = F32::wrap_vec(raw);
+        assert_eq!(wrapped, vec![F32(1.5), F32(f32::NAN), F32(0.0)]);
+        let raw = F32::unwrap_vec(wrapped);
+        assert_eq!(raw.as_ptr(), ptr);
+        assert_eq!(raw[0], 1.5);
+    }
+
+    #[test]
+    fn f64_wrap_slice() {
+        let raw = [1.5, f64::NAN, -0.0, 0.0];
+        let wrapped = F64::wrap_slice(&raw);
+        assert_eq!(wrapped.len(), raw.len());
+        for (w, r) in wrapped.iter().zip(raw.iter()) {
+            assert_eq!(*w, F64(*r));
+        }
+        assert_eq!(wrapped.iter().collect::<HashSet<_>>().len(), 3);
+        assert_eq!(F64::unwrap_slice(wrapped).as_ptr(), raw.as_ptr());
+
+        let mut raw = raw;
+        F64::wrap_slice_mut(&mut raw).sort();
+        assert!(raw[0].is_nan());
+        F64::unwrap_slice_mut(F64::wrap_slice_mut(&mut raw))[0] = 2.0;
+        assert_eq!(raw[0], 2.0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn f64_wrap_vec() {
+        let raw = vec![1.5, f64::NAN, -0.0];
+        let ptr = raw.as_ptr();
+        let wrapped = F64::wrap_vec(raw);
+        assert_eq!(wrapped, vec![F64(1.5), F64(f64::NAN), F64(0.0)]);
+        let raw = F64::unwrap_vec(wrapped);
+        assert_eq!(raw.as_ptr(), ptr);
+        assert_eq!(raw[0], 1.5);
+    }
+}
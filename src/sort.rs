@@ -0,0 +1,346 @@
+//! Sorting slices of wrappers by integer keys instead of by comparing floats.
+//!
+//! Every float is mapped in place to an unsigned integer whose order agrees with `Ord`, the
+//! integers are sorted, and then mapped back. The mapping is a bijection, so every bit pattern
+//! (including `NAN` payloads and the sign of zero) survives unchanged. It is the usual
+//! sign-flipping transformation that yields the IEEE 754 total order, followed by a wrapping
+//! offset that moves the positive `NAN`s from the very top to the very bottom, right below the
+//! negative ones.
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use core::mem;
+
+use crate::{F32, F64};
+
+/// Below this length, the radix sorts fall back to comparison sorting the keys.
+#[cfg(feature = "alloc")]
+const RADIX_SORT_THRESHOLD: usize = 256;
+
+const F32_SIGN: u32 = 1 << 31;
+/// The number of positive `NAN` bit patterns.
+const F32_NAN_OFFSET: u32 = (1 << 23) - 1;
+
+const F64_SIGN: u64 = 1 << 63;
+/// The number of positive `NAN` bit patterns.
+const F64_NAN_OFFSET: u64 = (1 << 52) - 1;
+
+fn f32_to_key(bits: u32) -> u32 {
+    let total = if bits & F32_SIGN == 0 {
+        bits | F32_SIGN
+    } else {
+        !bits
+    };
+    total.wrapping_add(F32_NAN_OFFSET)
+}
+
+fn f32_from_key(key: u32) -> u32 {
+    let total = key.wrapping_sub(F32_NAN_OFFSET);
+    if total & F32_SIGN == 0 {
+        !total
+    } else {
+        total & !F32_SIGN
+    }
+}
+
+fn f64_to_key(bits: u64) -> u64 {
+    let total = if bits & F64_SIGN == 0 {
+        bits | F64_SIGN
+    } else {
+        !bits
+    };
+    total.wrapping_add(F64_NAN_OFFSET)
+}
+
+fn f64_from_key(key: u64) -> u64 {
+    let total = key.wrapping_sub(F64_NAN_OFFSET);
+    if total & F64_SIGN == 0 {
+        !total
+    } else {
+        total & !F64_SIGN
+    }
+}
+
+macro_rules! impl_radix_sort {
+    ($name:ident, $int:ty) => {
+        /// Sorts the keys with a least significant digit radix sort on bytes, skipping the bytes
+        /// in which all keys agree.
+        #[cfg(feature = "alloc")]
+        fn $name(keys: &mut [$int]) {
+            if keys.len() < RADIX_SORT_THRESHOLD {
+                keys.sort_unstable();
+                return;
+            }
+
+            let len = keys.len();
+            let mut scratch = vec![0; len];
+            let (mut src, mut dst) = (keys, &mut scratch[..]);
+            let mut sorted_in_scratch = false;
+            for shift in (0..<$int>::BITS).step_by(8) {
+                let mut counts = [0usize; 256];
+                for key in src.iter() {
+                    counts[((key >> shift) & 0xff) as usize] += 1;
+                }
+                if counts.contains(&len) {
+                    continue;
+                }
+
+                let mut offset = 0;
+                for count in counts.iter_mut() {
+                    let start = offset;
+                    offset += *count;
+                    *count = start;
+                }
+                for key in src.iter() {
+                    let digit = ((key >> shift) & 0xff) as usize;
+                    dst[counts[digit]] = *key;
+                    counts[digit] += 1;
+                }
+                mem::swap(&mut src, &mut dst);
+                sorted_in_scratch = !sorted_in_scratch;
+            }
+
+            if sorted_in_scratch {
+                dst.copy_from_slice(src);
+            }
+        }
+    };
+}
+
+impl_radix_sort!(radix_sort_u32, u32);
+impl_radix_sort!(radix_sort_u64, u64);
+
+impl F32 {
+    /// Sorts the slice like `<[F32]>::sort_unstable` does, but faster for large slices, since it
+    /// sorts integer keys rather than comparing floats. The result is ordered exactly like `Ord`
+    /// orders it. Values that are equal but have different bits (different `NAN`s, or `0.0` and
+    /// `-0.0`) may end up in any relative order.
+    pub fn sort_unstable_floats(s: &mut [F32]) {
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`, which has the same
+        // size and alignment as `u32`, and every bit pattern is valid for both types.
+        let keys = unsafe { &mut *(s as *mut [F32] as *mut [u32]) };
+        for key in keys.iter_mut() {
+            *key = f32_to_key(*key);
+        }
+        keys.sort_unstable();
+        for key in keys.iter_mut() {
+            *key = f32_from_key(*key);
+        }
+    }
+
+    /// Sorts the slice like `sort_unstable_floats` does, but with a radix sort, which is faster
+    /// for very large slices. The result is ordered exactly like `Ord` orders it, and values that
+    /// are equal but have different bits end up in an order that only depends on their bits
+    /// (e.g. `-0.0` before `0.0`). Short slices are sorted with a comparison sort instead. This
+    /// allocates a buffer as large as the slice.
+    #[cfg(feature = "alloc")]
+    pub fn radix_sort_floats(s: &mut [F32]) {
+        // Safe because `F32` is a `repr(transparent)` wrapper around `f32`, which has the same
+        // size and alignment as `u32`, and every bit pattern is valid for both types.
+        let keys = unsafe { &mut *(s as *mut [F32] as *mut [u32]) };
+        for key in keys.iter_mut() {
+            *key = f32_to_key(*key);
+        }
+        radix_sort_u32(keys);
+        for key in keys.iter_mut() {
+            *key = f32_from_key(*key);
+        }
+    }
+
+    /// Sorts a slice of primitives like `radix_sort_floats` sorts the wrapped values, i.e. by
+    /// `Ord` on `F32`.
+    #[cfg(feature = "alloc")]
+    pub fn radix_sort_total(s: &mut [f32]) {
+        F32::radix_sort_floats(F32::wrap_slice_mut(s))
+    }
+}
+
+impl F64 {
+    /// Sorts the slice like `<[F64]>::sort_unstable` does, but faster for large slices, since it
+    /// sorts integer keys rather than comparing floats. The result is ordered exactly like `Ord`
+    /// orders it. Values that are equal but have different bits (different `NAN`s, or `0.0` and
+    /// `-0.0`) may end up in any relative order.
+    pub fn sort_unstable_floats(s: &mut [F64]) {
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`, which has the same
+        // size and alignment as `u64`, and every bit pattern is valid for both types.
+        let keys = unsafe { &mut *(s as *mut [F64] as *mut [u64]) };
+        for key in keys.iter_mut() {
+            *key = f64_to_key(*key);
+        }
+        keys.sort_unstable();
+        for key in keys.iter_mut() {
+            *key = f64_from_key(*key);
+        }
+    }
+
+    /// Sorts the slice like `sort_unstable_floats` does, but with a radix sort, which is faster
+    /// for very large slices. The result is ordered exactly like `Ord` orders it, and values that
+    /// are equal but have different bits end up in an order that only depends on their bits
+    /// (e.g. `-0.0` before `0.0`). Short slices are sorted with a comparison sort instead. This
+    /// allocates a buffer as large as the slice.
+    #[cfg(feature = "alloc")]
+    pub fn radix_sort_floats(s: &mut [F64]) {
+        // Safe because `F64` is a `repr(transparent)` wrapper around `f64`, which has the same
+        // size and alignment as `u64`, and every bit pattern is valid for both types.
+        let keys = unsafe { &mut *(s as *mut [F64] as *mut [u64]) };
+        for key in keys.iter_mut() {
+            *key = f64_to_key(*key);
+        }
+        radix_sort_u64(keys);
+        for key in keys.iter_mut() {
+            *key = f64_from_key(*key);
+        }
+    }
+
+    /// Sorts a slice of primitives like `radix_sort_floats` sorts the wrapped values, i.e. by
+    /// `Ord` on `F64`.
+    #[cfg(feature = "alloc")]
+    pub fn radix_sort_total(s: &mut [f64]) {
+        F64::radix_sort_floats(F64::wrap_slice_mut(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{random_bits_u32, random_bits_u64};
+
+    #[test]
+    fn keys_round_trip() {
+        for bits in [
+            0,
+            1,
+            F32_SIGN,
+            u32::MAX,
+            f32::NAN.to_bits(),
+            f32::INFINITY.to_bits(),
+        ] {
+            assert_eq!(f32_from_key(f32_to_key(bits)), bits);
+        }
+        for bits in [
+            0,
+            1,
+            F64_SIGN,
+            u64::MAX,
+            f64::NAN.to_bits(),
+            f64::INFINITY.to_bits(),
+        ] {
+            assert_eq!(f64_from_key(f64_to_key(bits)), bits);
+        }
+    }
+
+    #[test]
+    fn sort_f32_like_ord() {
+        let mut v: Vec<F32> = random_bits_u32(0x2545_f491_4f6c_dd1d)
+            .take(2000)
+            .map(|bits| F32(f32::from_bits(bits)))
+            .collect();
+        v.extend_from_slice(&[
+            F32(f32::NAN),
+            F32(-f32::NAN),
+            F32(0.0),
+            F32(-0.0),
+            F32(f32::INFINITY),
+            F32(f32::NEG_INFINITY),
+            F32(f32::from_bits(1)),
+            F32(-f32::from_bits(1)),
+        ]);
+
+        let mut expected = v.clone();
+        expected.sort_unstable();
+        let mut bits: Vec<u32> = v.iter().map(|f| f.0.to_bits()).collect();
+        bits.sort_unstable();
+
+        F32::sort_unstable_floats(&mut v);
+        assert_eq!(v, expected);
+        let mut sorted_bits: Vec<u32> = v.iter().map(|f| f.0.to_bits()).collect();
+        sorted_bits.sort_unstable();
+        assert_eq!(sorted_bits, bits);
+    }
+
+    #[test]
+    fn sort_f64_like_ord() {
+        let mut v: Vec<F64> = random_bits_u64(0x9e37_79b9_7f4a_7c15)
+            .take(2000)
+            .map(|bits| F64(f64::from_bits(bits)))
+            .collect();
+        v.extend_from_slice(&[
+            F64(f64::NAN),
+            F64(-f64::NAN),
+            F64(0.0),
+            F64(-0.0),
+            F64(f64::INFINITY),
+            F64(f64::NEG_INFINITY),
+            F64(f64::from_bits(1)),
+            F64(-f64::from_bits(1)),
+        ]);
+
+        let mut expected = v.clone();
+        expected.sort_unstable();
+        let mut bits: Vec<u64> = v.iter().map(|f| f.0.to_bits()).collect();
+        bits.sort_unstable();
+
+        F64::sort_unstable_floats(&mut v);
+        assert_eq!(v, expected);
+        let mut sorted_bits: Vec<u64> = v.iter().map(|f| f.0.to_bits()).collect();
+        sorted_bits.sort_unstable();
+        assert_eq!(sorted_bits, bits);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn radix_sort_like_ord() {
+        let mut rng = random_bits_u64(0x2545_f491_4f6c_dd1d);
+        for len in [0, 10, RADIX_SORT_THRESHOLD, 5000] {
+            let mut v: Vec<F32> = (0..len)
+                .map(|i| match i % 8 {
+                    0 => F32(f32::NAN),
+                    1 => F32(-0.0),
+                    2 => F32(0.0),
+                    3 => F32(f32::from_bits(rng.next().unwrap() as u32 & 0x807f_ffff)),
+                    _ => F32(f32::from_bits(rng.next().unwrap() as u32)),
+                })
+                .collect();
+            let mut expected = v.clone();
+            expected.sort_unstable();
+            F32::radix_sort_floats(&mut v);
+            assert_eq!(v, expected);
+
+            let mut w: Vec<F64> = (0..len)
+                .map(|i| match i % 8 {
+                    0 => F64(-f64::NAN),
+                    1 => F64(-0.0),
+                    2 => F64(0.0),
+                    3 => F64(f64::from_bits(rng.next().unwrap() & 0x800f_ffff_ffff_ffff)),
+                    _ => F64(f64::from_bits(rng.next().unwrap())),
+                })
+                .collect();
+            let mut expected = w.clone();
+            expected.sort_unstable();
+            F64::radix_sort_floats(&mut w);
+            assert_eq!(w, expected);
+
+            let mut primitives: Vec<f32> = F32::unwrap_slice(&v).iter().rev().copied().collect();
+            F32::radix_sort_total(&mut primitives);
+            assert_eq!(F32::wrap_slice(&primitives), &v[..]);
+            let mut primitives: Vec<f64> = F64::unwrap_slice(&w).iter().rev().copied().collect();
+            F64::radix_sort_total(&mut primitives);
+            assert_eq!(F64::wrap_slice(&primitives), &w[..]);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn radix_sort_equal_values() {
+        let mut v: Vec<F64> = (0..1000)
+            .map(|i| F64(if i % 2 == 0 { -0.0 } else { 0.0 }))
+            .collect();
+        v.push(F64(-1.0));
+        F64::radix_sort_floats(&mut v);
+        assert_eq!(v[0], F64(-1.0));
+        for (i, f) in v[1..].iter().enumerate() {
+            assert_eq!(f.0.is_sign_negative(), i < 500);
+        }
+    }
+}
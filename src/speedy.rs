@@ -0,0 +1,107 @@
+//! `speedy` support. Values are written as the raw bits of the inner float, so every bit pattern
+//! (including `NAN` payloads and signs) survives a round trip unchanged.
+
+use speedy::{Context, Readable, Reader, Writable, Writer};
+
+use crate::{F32, F64};
+
+impl<'a, C: Context> Readable<'a, C> for F32 {
+    #[inline]
+    fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        reader.read_u32().map(f32::from_bits).map(F32)
+    }
+
+    #[inline]
+    fn minimum_bytes_needed() -> usize {
+        4
+    }
+}
+
+impl<C: Context> Writable<C> for F32 {
+    #[inline]
+    fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        writer.write_u32(self.0.to_bits())
+    }
+
+    #[inline]
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(4)
+    }
+}
+
+impl<'a, C: Context> Readable<'a, C> for F64 {
+    #[inline]
+    fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        reader.read_u64().map(f64::from_bits).map(F64)
+    }
+
+    #[inline]
+    fn minimum_bytes_needed() -> usize {
+        8
+    }
+}
+
+impl<C: Context> Writable<C> for F64 {
+    #[inline]
+    fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        writer.write_u64(self.0.to_bits())
+    }
+
+    #[inline]
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use speedy::{Endianness, Readable, Writable};
+
+    use crate::{F32, F64};
+
+    #[test]
+    fn speedy_f32_round_trip() {
+        let values = [
+            0.0,
+            -0.0,
+            1.5,
+            f32::MIN_POSITIVE,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+            -f32::NAN,
+            f32::from_bits(0x7f80_0001), // signalling NAN with a payload
+        ];
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            for v in values {
+                let bytes = F32(v).write_to_vec_with_ctx(endianness).unwrap();
+                assert_eq!(bytes.len(), 4);
+                let back = F32::read_from_buffer_with_ctx(endianness, &bytes).unwrap();
+                assert_eq!(back.0.to_bits(), v.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn speedy_f64_round_trip() {
+        let values = [
+            0.0,
+            -0.0,
+            1.5,
+            f64::MIN_POSITIVE,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -f64::NAN,
+            f64::from_bits(0x7ff0_0000_0000_0001), // signalling NAN with a payload
+        ];
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            for v in values {
+                let bytes = F64(v).write_to_vec_with_ctx(endianness).unwrap();
+                assert_eq!(bytes.len(), 8);
+                let back = F64::read_from_buffer_with_ctx(endianness, &bytes).unwrap();
+                assert_eq!(back.0.to_bits(), v.to_bits());
+            }
+        }
+    }
+}
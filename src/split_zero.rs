@@ -0,0 +1,197 @@
+//! Wrappers that order `-0.0` below `0.0` but, like `F32` and `F64`, consider all `NAN`s equal
+//! and less than all other values.
+//!
+//! This sits between `F32`/`F64` (which collapse the zeros) and `TotalF32`/`TotalF64` (which
+//! also distinguish `NAN`s by their bits).
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::{CANONICAL_NAN_BITS_F32, CANONICAL_NAN_BITS_F64};
+
+/// A wrapper around `f32` that considers all `NAN`s equal and less than all other values, and
+/// `-0.0` less than `0.0`.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct SplitZero32(pub f32);
+
+/// All `NAN`s are equal, other values are equal if and only if they have the same bits.
+impl PartialEq for SplitZero32 {
+    fn eq(&self, other: &Self) -> bool {
+        if self.0.is_nan() || other.0.is_nan() {
+            self.0.is_nan() && other.0.is_nan()
+        } else {
+            self.0.to_bits() == other.0.to_bits()
+        }
+    }
+}
+
+impl Eq for SplitZero32 {}
+
+/// This always returns a `Some`.
+impl PartialOrd for SplitZero32 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// `NAN` is less than all other values, `-0.0` is less than `0.0`.
+impl Ord for SplitZero32 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self.0.total_cmp(&other.0),
+        }
+    }
+}
+
+impl Hash for SplitZero32 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.0.is_nan() {
+            CANONICAL_NAN_BITS_F32.hash(state);
+        } else {
+            self.0.to_bits().hash(state);
+        }
+    }
+}
+
+impl From<SplitZero32> for f32 {
+    fn from(f: SplitZero32) -> Self {
+        f.0
+    }
+}
+
+impl From<f32> for SplitZero32 {
+    fn from(f: f32) -> Self {
+        SplitZero32(f)
+    }
+}
+
+impl fmt::Display for SplitZero32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A wrapper around `f64` that considers all `NAN`s equal and less than all other values, and
+/// `-0.0` less than `0.0`.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct SplitZero64(pub f64);
+
+/// All `NAN`s are equal, other values are equal if and only if they have the same bits.
+impl PartialEq for SplitZero64 {
+    fn eq(&self, other: &Self) -> bool {
+        if self.0.is_nan() || other.0.is_nan() {
+            self.0.is_nan() && other.0.is_nan()
+        } else {
+            self.0.to_bits() == other.0.to_bits()
+        }
+    }
+}
+
+impl Eq for SplitZero64 {}
+
+/// This always returns a `Some`.
+impl PartialOrd for SplitZero64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// `NAN` is less than all other values, `-0.0` is less than `0.0`.
+impl Ord for SplitZero64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self.0.total_cmp(&other.0),
+        }
+    }
+}
+
+impl Hash for SplitZero64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.0.is_nan() {
+            CANONICAL_NAN_BITS_F64.hash(state);
+        } else {
+            self.0.to_bits().hash(state);
+        }
+    }
+}
+
+impl From<SplitZero64> for f64 {
+    fn from(f: SplitZero64) -> Self {
+        f.0
+    }
+}
+
+impl From<f64> for SplitZero64 {
+    fn from(f: f64) -> Self {
+        SplitZero64(f)
+    }
+}
+
+impl fmt::Display for SplitZero64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::{SplitZero32, SplitZero64};
+
+    fn calculate_hash<T: Hash>(t: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+
+    #[test]
+    fn split_zero32() {
+        let nan = SplitZero32(f32::NAN);
+        let other_nan = SplitZero32(-f32::from_bits(f32::NAN.to_bits() | 1));
+        assert!(nan == other_nan);
+        assert_eq!(calculate_hash(&nan), calculate_hash(&other_nan));
+        assert!(nan < SplitZero32(f32::NEG_INFINITY));
+        assert!(other_nan < SplitZero32(f32::NEG_INFINITY));
+
+        assert!(SplitZero32(-0.0) != SplitZero32(0.0));
+        assert!(SplitZero32(-0.0) < SplitZero32(0.0));
+        assert!(calculate_hash(&SplitZero32(-0.0)) != calculate_hash(&SplitZero32(0.0)));
+        assert!(SplitZero32(-f32::from_bits(1)) < SplitZero32(-0.0));
+        assert!(SplitZero32(0.0) < SplitZero32(f32::from_bits(1)));
+
+        assert!(SplitZero32(-1.0) < SplitZero32(1.0));
+        assert!(SplitZero32(1.0) < SplitZero32(f32::INFINITY));
+        assert!(SplitZero32(1.5) == SplitZero32(1.5));
+    }
+
+    #[test]
+    fn split_zero64() {
+        let nan = SplitZero64(f64::NAN);
+        let other_nan = SplitZero64(-f64::from_bits(f64::NAN.to_bits() | 1));
+        assert!(nan == other_nan);
+        assert_eq!(calculate_hash(&nan), calculate_hash(&other_nan));
+        assert!(nan < SplitZero64(f64::NEG_INFINITY));
+        assert!(other_nan < SplitZero64(f64::NEG_INFINITY));
+
+        assert!(SplitZero64(-0.0) != SplitZero64(0.0));
+        assert!(SplitZero64(-0.0) < SplitZero64(0.0));
+        assert!(calculate_hash(&SplitZero64(-0.0)) != calculate_hash(&SplitZero64(0.0)));
+        assert!(SplitZero64(-f64::from_bits(1)) < SplitZero64(-0.0));
+        assert!(SplitZero64(0.0) < SplitZero64(f64::from_bits(1)));
+
+        assert!(SplitZero64(-1.0) < SplitZero64(1.0));
+        assert!(SplitZero64(1.0) < SplitZero64(f64::INFINITY));
+        assert!(SplitZero64(1.5) == SplitZero64(1.5));
+    }
+}
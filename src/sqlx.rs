@@ -0,0 +1,88 @@
+//! `sqlx` support. `F32` and `F64` are encoded and decoded exactly like `f32` and `f64`, for every
+//! database that supports those (`REAL` and `DOUBLE PRECISION` in Postgres, `REAL` in SQLite).
+//!
+//! Note that SQLite stores `NAN` as `NULL`, so a `NAN` does not survive a round trip there,
+//! whereas it does in Postgres.
+
+use sqlx::decode::Decode;
+use sqlx::encode::{Encode, IsNull};
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Type};
+
+use crate::{F32, F64};
+
+impl<DB: Database> Type<DB> for F32
+where
+    f32: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <f32 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <f32 as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for F32
+where
+    f32: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.0.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for F32
+where
+    f32: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        f32::decode(value).map(F32)
+    }
+}
+
+impl<DB: Database> Type<DB> for F64
+where
+    f64: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <f64 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <f64 as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for F64
+where
+    f64: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.0.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for F64
+where
+    f64: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        f64::decode(value).map(F64)
+    }
+}
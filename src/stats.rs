@@ -0,0 +1,123 @@
+//! Summary statistics over slices of `F64`, with an explicit choice of how to treat `NAN`.
+//!
+//! Every function returns `None` for an empty slice, and also for a slice containing only `NAN`
+//! under `NanPolicy::Skip`.
+
+use crate::F64;
+
+/// How the functions of this module treat `NAN` elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// A single `NAN` element makes the result `NAN`.
+    Propagate,
+    /// `NAN` elements are ignored.
+    Skip,
+}
+
+/// Applies the policy: returns `Err` with the result for a slice poisoned by a `NAN`, or `Ok`
+/// with the elements to compute the result from.
+fn elements(xs: &[F64], policy: NanPolicy) -> Result<impl Iterator<Item = F64> + '_, F64> {
+    if policy == NanPolicy::Propagate && xs.iter().any(|x| x.0.is_nan()) {
+        Err(F64(f64::NAN))
+    } else {
+        Ok(xs.iter().copied().filter(|x| !x.0.is_nan()))
+    }
+}
+
+/// Returns the arithmetic mean of the elements.
+pub fn mean(xs: &[F64], policy: NanPolicy) -> Option<F64> {
+    match elements(xs, policy) {
+        Err(nan) => Some(nan),
+        Ok(elements) => {
+            let (sum, count) =
+                elements.fold((0.0, 0usize), |(sum, count), x| (sum + x.0, count + 1));
+            if count == 0 {
+                None
+            } else {
+                Some(F64(sum / count as f64))
+            }
+        }
+    }
+}
+
+/// Returns the population variance of the elements (the mean of the squared deviations from
+/// the mean).
+pub fn variance(xs: &[F64], policy: NanPolicy) -> Option<F64> {
+    let mean = mean(xs, policy)?;
+    match elements(xs, policy) {
+        Err(nan) => Some(nan),
+        Ok(elements) => {
+            let (sum, count) = elements.fold((0.0, 0usize), |(sum, count), x| {
+                let deviation = x.0 - mean.0;
+                (sum + deviation * deviation, count + 1)
+            });
+            Some(F64(sum / count as f64))
+        }
+    }
+}
+
+/// Returns the least element.
+pub fn min(xs: &[F64], policy: NanPolicy) -> Option<F64> {
+    match elements(xs, policy) {
+        Err(nan) => Some(nan),
+        Ok(elements) => elements.min(),
+    }
+}
+
+/// Returns the greatest element.
+pub fn max(xs: &[F64], policy: NanPolicy) -> Option<F64> {
+    match elements(xs, policy) {
+        Err(nan) => Some(nan),
+        Ok(elements) => elements.max(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLEAN: [F64; 4] = [F64(2.0), F64(4.0), F64(4.0), F64(6.0)];
+    const WITH_NAN: [F64; 5] = [F64(2.0), F64(4.0), F64(f64::NAN), F64(4.0), F64(6.0)];
+
+    #[test]
+    fn clean() {
+        for policy in [NanPolicy::Propagate, NanPolicy::Skip] {
+            assert_eq!(mean(&CLEAN, policy), Some(F64(4.0)));
+            assert_eq!(variance(&CLEAN, policy), Some(F64(2.0)));
+            assert_eq!(min(&CLEAN, policy), Some(F64(2.0)));
+            assert_eq!(max(&CLEAN, policy), Some(F64(6.0)));
+        }
+    }
+
+    #[test]
+    fn propagate() {
+        let nan = Some(F64(f64::NAN));
+        assert_eq!(mean(&WITH_NAN, NanPolicy::Propagate), nan);
+        assert_eq!(variance(&WITH_NAN, NanPolicy::Propagate), nan);
+        assert_eq!(min(&WITH_NAN, NanPolicy::Propagate), nan);
+        assert_eq!(max(&WITH_NAN, NanPolicy::Propagate), nan);
+    }
+
+    #[test]
+    fn skip() {
+        assert_eq!(mean(&WITH_NAN, NanPolicy::Skip), Some(F64(4.0)));
+        assert_eq!(variance(&WITH_NAN, NanPolicy::Skip), Some(F64(2.0)));
+        assert_eq!(min(&WITH_NAN, NanPolicy::Skip), Some(F64(2.0)));
+        assert_eq!(max(&WITH_NAN, NanPolicy::Skip), Some(F64(6.0)));
+    }
+
+    #[test]
+    fn empty() {
+        let nans = [F64(f64::NAN)];
+        for policy in [NanPolicy::Propagate, NanPolicy::Skip] {
+            assert_eq!(mean(&[], policy), None);
+            assert_eq!(variance(&[], policy), None);
+            assert_eq!(min(&[], policy), None);
+            assert_eq!(max(&[], policy), None);
+        }
+        assert_eq!(mean(&nans, NanPolicy::Skip), None);
+        assert_eq!(variance(&nans, NanPolicy::Skip), None);
+        assert_eq!(max(&nans, NanPolicy::Skip), None);
+        assert_eq!(max(&nans, NanPolicy::Propagate), Some(F64(f64::NAN)));
+    }
+}
@@ -0,0 +1,79 @@
+//! `checked_sum`, which sums `F64`s and reports a `NAN` input or a non-finite result as a
+//! `SumError` instead of returning it.
+
+use core::fmt;
+
+use crate::F64;
+
+/// The error returned by `checked_sum`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SumError {
+    /// An input was `NAN`; `index` is the position of the first such input.
+    NaNEncountered { index: usize },
+    /// No input was `NAN`, but the sum is not finite, because it overflowed or because an input
+    /// was infinite.
+    Overflow,
+}
+
+impl fmt::Display for SumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SumError::NaNEncountered { index } => write!(f, "input at index {} is NaN", index),
+            SumError::Overflow => write!(f, "sum is not finite"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SumError {}
+
+/// Sums the values, reporting instead of returning a `NAN` or infinite result.
+///
+/// The sum of no values is `0.0`.
+pub fn checked_sum<I: IntoIterator<Item = F64>>(iter: I) -> Result<F64, SumError> {
+    let mut sum = 0.0;
+    for (index, x) in iter.into_iter().enumerate() {
+        if x.0.is_nan() {
+            return Err(SumError::NaNEncountered { index });
+        }
+        sum += x.0;
+    }
+
+    if sum.is_finite() {
+        Ok(F64(sum))
+    } else {
+        Err(SumError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean() {
+        assert_eq!(
+            checked_sum(vec![F64(1.0), F64(2.5), F64(-0.5)]),
+            Ok(F64(3.0))
+        );
+        assert_eq!(checked_sum(vec![]), Ok(F64(0.0)));
+    }
+
+    #[test]
+    fn nan() {
+        let xs = vec![F64(1.0), F64(f64::INFINITY), F64(f64::NAN), F64(f64::NAN)];
+        assert_eq!(checked_sum(xs), Err(SumError::NaNEncountered { index: 2 }));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            checked_sum(vec![F64(f64::MAX), F64(f64::MAX)]),
+            Err(SumError::Overflow)
+        );
+        assert_eq!(
+            checked_sum(vec![F64(f64::INFINITY), F64(f64::NEG_INFINITY)]),
+            Err(SumError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Wrappers whose `Eq`, `Ord` and `Hash` all follow the IEEE 754 `totalOrder` predicate (as
+//! implemented by `f32::total_cmp` and `f64::total_cmp`), for bit-faithful set semantics.
+//!
+//! Unlike with `F32` and `F64`, `-0.0` and `0.0` are distinct (`-0.0` is less), and `NAN`s are
+//! distinct whenever their bits differ: negative `NAN`s sort below all other values, positive
+//! `NAN`s above all other values. `TotalOrd32` and `TotalOrd64` are aliases of these types.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::{F32, F64};
+
+/// An alias for `TotalF32`, named after the IEEE 754 `totalOrder` predicate.
+pub type TotalOrd32 = TotalF32;
+
+/// An alias for `TotalF64`, named after the IEEE 754 `totalOrder` predicate.
+pub type TotalOrd64 = TotalF64;
+
+/// A wrapper around `f32` whose comparisons and hashing follow `f32::total_cmp`.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct TotalF32(pub f32);
+
+/// Two values are equal if and only if they have the same bits.
+impl PartialEq for TotalF32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for TotalF32 {}
+
+/// This works like `f32::total_cmp`. This always returns a `Some`.
+impl PartialOrd for TotalF32 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// This works like `f32::total_cmp`.
+impl Ord for TotalF32 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for TotalF32 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl From<TotalF32> for f32 {
+    fn from(f: TotalF32) -> Self {
+        f.0
+    }
+}
+
+impl From<f32> for TotalF32 {
+    fn from(f: f32) -> Self {
+        TotalF32(f)
+    }
+}
+
+/// Keeps the bits, so the `NAN` payload and the sign of zero survive.
+impl From<F32> for TotalF32 {
+    fn from(f: F32) -> Self {
+        TotalF32(f.0)
+    }
+}
+
+/// Keeps the bits, so the `NAN` payload and the sign of zero survive.
+impl From<TotalF32> for F32 {
+    fn from(f: TotalF32) -> Self {
+        F32(f.0)
+    }
+}
+
+impl fmt::Display for TotalF32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A wrapper around `f64` whose comparisons and hashing follow `f64::total_cmp`.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct TotalF64(pub f64);
+
+/// Two values are equal if and only if they have the same bits.
+impl PartialEq for TotalF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for TotalF64 {}
+
+/// This works like `f64::total_cmp`. This always returns a `Some`.
+impl PartialOrd for TotalF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// This works like `f64::total_cmp`.
+impl Ord for TotalF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for TotalF64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl From<TotalF64> for f64 {
+    fn from(f: TotalF64) -> Self {
+        f.0
+    }
+}
+
+impl From<f64> for TotalF64 {
+    fn from(f: f64) -> Self {
+        TotalF64(f)
+    }
+}
+
+/// Keeps the bits, so the `NAN` payload and the sign of zero survive.
+impl From<F64> for TotalF64 {
+    fn from(f: F64) -> Self {
+        TotalF64(f.0)
+    }
+}
+
+/// Keeps the bits, so the `NAN` payload and the sign of zero survive.
+impl From<TotalF64> for F64 {
+    fn from(f: TotalF64) -> Self {
+        F64(f.0)
+    }
+}
+
+impl fmt::Display for TotalF64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::{TotalF32, TotalF64, TotalOrd32, TotalOrd64};
+    use crate::{F32, F64};
+
+    fn calculate_hash<T: Hash>(t: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+
+    #[test]
+    fn total_f32() {
+        assert!(TotalF32(-0.0) != TotalF32(0.0));
+        assert!(TotalF32(-0.0) < TotalF32(0.0));
+        assert!(calculate_hash(&TotalF32(-0.0)) != calculate_hash(&TotalF32(0.0)));
+        assert!(TotalF32(f32::NAN) == TotalF32(f32::NAN));
+        assert!(TotalF32(-f32::NAN) != TotalF32(f32::NAN));
+        assert!(TotalF32(-f32::NAN) < TotalF32(f32::NEG_INFINITY));
+        assert!(TotalF32(f32::NAN) > TotalF32(f32::INFINITY));
+    }
+
+    #[test]
+    fn total_ord32_order() {
+        let quiet = f32::NAN.to_bits();
+        let ordered = [
+            -f32::from_bits(quiet | 1),
+            -f32::NAN,
+            f32::NEG_INFINITY,
+            -1.0,
+            -f32::from_bits(1),
+            -0.0,
+            0.0,
+            f32::from_bits(1),
+            1.0,
+            f32::INFINITY,
+            f32::NAN,
+            f32::from_bits(quiet | 1),
+        ];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(TotalOrd32::from(*a).cmp(&TotalOrd32::from(*b)), i.cmp(&j));
+            }
+        }
+        assert!(TotalOrd32::from(f32::NAN) != TotalOrd32::from(f32::from_bits(quiet | 1)));
+    }
+
+    #[test]
+    fn total_ord32_conversions() {
+        let nan = f32::from_bits(f32::NAN.to_bits() | 1);
+        assert_eq!(F32::from(TotalOrd32::from(F32(nan))).0.to_bits(), nan.to_bits());
+        assert_eq!(f32::from(TotalOrd32::from(-0.0)).to_bits(), (-0.0f32).to_bits());
+    }
+
+    #[test]
+    fn total_f64() {
+        assert!(TotalF64(-0.0) != TotalF64(0.0));
+        assert!(TotalF64(-0.0) < TotalF64(0.0));
+        assert!(calculate_hash(&TotalF64(-0.0)) != calculate_hash(&TotalF64(0.0)));
+        assert!(TotalF64(f64::NAN) == TotalF64(f64::NAN));
+        assert!(TotalF64(-f64::NAN) != TotalF64(f64::NAN));
+        assert!(TotalF64(-f64::NAN) < TotalF64(f64::NEG_INFINITY));
+        assert!(TotalF64(f64::NAN) > TotalF64(f64::INFINITY));
+    }
+
+    #[test]
+    fn total_ord64_order() {
+        let quiet = f64::NAN.to_bits();
+        let ordered = [
+            -f64::from_bits(quiet | 1),
+            -f64::NAN,
+            f64::NEG_INFINITY,
+            -1.0,
+            -f64::from_bits(1),
+            -0.0,
+            0.0,
+            f64::from_bits(1),
+            1.0,
+            f64::INFINITY,
+            f64::NAN,
+            f64::from_bits(quiet | 1),
+        ];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(TotalOrd64::from(*a).cmp(&TotalOrd64::from(*b)), i.cmp(&j));
+            }
+        }
+        assert!(TotalOrd64::from(f64::NAN) != TotalOrd64::from(f64::from_bits(quiet | 1)));
+    }
+
+    #[test]
+    fn total_ord64_conversions() {
+        let nan = f64::from_bits(f64::NAN.to_bits() | 1);
+        assert_eq!(F64::from(TotalOrd64::from(F64(nan))).0.to_bits(), nan.to_bits());
+        assert_eq!(f64::from(TotalOrd64::from(-0.0)).to_bits(), (-0.0f64).to_bits());
+    }
+}
@@ -0,0 +1,120 @@
+//! `wasm-bindgen` support. `F32` and `F64` cross the JS boundary as numbers, both as `JsValue`s
+//! and directly in the signatures of `#[wasm_bindgen]` functions. `NAN` maps to the JS `NaN` and
+//! back.
+
+use core::convert::TryFrom;
+
+use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi};
+use wasm_bindgen::describe::WasmDescribe;
+use wasm_bindgen::JsValue;
+
+use crate::{F32, F64};
+
+impl From<F32> for JsValue {
+    fn from(f: F32) -> Self {
+        JsValue::from_f64(f64::from(f.0))
+    }
+}
+
+/// Fails with the original value if it is not a JS number.
+impl TryFrom<JsValue> for F32 {
+    type Error = JsValue;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        match value.as_f64() {
+            Some(f) => Ok(F32(f as f32)),
+            None => Err(value),
+        }
+    }
+}
+
+impl WasmDescribe for F32 {
+    fn describe() {
+        f32::describe()
+    }
+}
+
+impl IntoWasmAbi for F32 {
+    type Abi = <f32 as IntoWasmAbi>::Abi;
+
+    fn into_abi(self) -> Self::Abi {
+        self.0.into_abi()
+    }
+}
+
+impl FromWasmAbi for F32 {
+    type Abi = <f32 as FromWasmAbi>::Abi;
+
+    unsafe fn from_abi(js: Self::Abi) -> Self {
+        F32(f32::from_abi(js))
+    }
+}
+
+impl From<F64> for JsValue {
+    fn from(f: F64) -> Self {
+        JsValue::from_f64(f.0)
+    }
+}
+
+/// Fails with the original value if it is not a JS number.
+impl TryFrom<JsValue> for F64 {
+    type Error = JsValue;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        match value.as_f64() {
+            Some(f) => Ok(F64(f)),
+            None => Err(value),
+        }
+    }
+}
+
+impl WasmDescribe for F64 {
+    fn describe() {
+        f64::describe()
+    }
+}
+
+impl IntoWasmAbi for F64 {
+    type Abi = <f64 as IntoWasmAbi>::Abi;
+
+    fn into_abi(self) -> Self::Abi {
+        self.0.into_abi()
+    }
+}
+
+impl FromWasmAbi for F64 {
+    type Abi = <f64 as FromWasmAbi>::Abi;
+
+    unsafe fn from_abi(js: Self::Abi) -> Self {
+        F64(f64::from_abi(js))
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use std::convert::TryFrom;
+
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::{F32, F64};
+
+    #[wasm_bindgen_test]
+    fn js_number_round_trip() {
+        for f in [f64::NAN, -0.0, 1.5, f64::INFINITY] {
+            let js = JsValue::from(F64(f));
+            assert_eq!(js.as_f64().map(F64), Some(F64(f)));
+            assert_eq!(F64::try_from(js).unwrap(), F64(f));
+        }
+
+        let js = JsValue::from(F32(f32::NAN));
+        assert!(js.as_f64().unwrap().is_nan());
+        assert_eq!(F32::try_from(js).unwrap(), F32(f32::NAN));
+    }
+
+    #[wasm_bindgen_test]
+    fn not_a_number() {
+        assert!(F64::try_from(JsValue::from_str("1.5")).is_err());
+        assert!(F32::try_from(JsValue::NULL).is_err());
+    }
+}
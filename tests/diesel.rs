@@ -0,0 +1,80 @@
+#![cfg(feature = "diesel")]
+
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+use eq_float::{F32, F64};
+
+diesel::table! {
+    scores (id) {
+        id -> Integer,
+        single -> Float,
+        double -> Nullable<Double>,
+    }
+}
+
+#[derive(Debug, PartialEq, Queryable, Insertable)]
+#[diesel(table_name = scores)]
+struct Score {
+    single: F32,
+    double: Option<F64>,
+}
+
+#[test]
+fn sqlite_round_trip() {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query(
+        "CREATE TABLE scores (id INTEGER PRIMARY KEY, single REAL NOT NULL, double REAL)",
+    )
+    .execute(&mut conn)
+    .unwrap();
+
+    let scores = [
+        Score {
+            single: F32(1.5),
+            double: Some(F64(-0.0)),
+        },
+        Score {
+            single: F32(-0.0),
+            double: Some(F64(123.456)),
+        },
+        Score {
+            single: F32(f32::INFINITY),
+            double: None,
+        },
+    ];
+    diesel::insert_into(scores::table)
+        .values(&scores[..])
+        .execute(&mut conn)
+        .unwrap();
+
+    let read: Vec<Score> = scores::table
+        .select((scores::single, scores::double))
+        .order(scores::id)
+        .load(&mut conn)
+        .unwrap();
+    assert_eq!(read, scores);
+}
+
+#[test]
+fn sqlite_stores_nan_as_null() {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query(
+        "CREATE TABLE scores (id INTEGER PRIMARY KEY, single REAL NOT NULL, double REAL)",
+    )
+    .execute(&mut conn)
+    .unwrap();
+
+    diesel::insert_into(scores::table)
+        .values(&Score {
+            single: F32(0.0),
+            double: Some(F64(f64::NAN)),
+        })
+        .execute(&mut conn)
+        .unwrap();
+
+    let double: Option<F64> = scores::table
+        .select(scores::double)
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(double, None);
+}
@@ -0,0 +1,31 @@
+//! Checks that the crate builds without the standard library (and without `alloc`), by building
+//! it with the default features disabled.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+fn build(features: &[&str]) {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .current_dir(manifest_dir)
+        .args(["build", "--lib", "--no-default-features"])
+        .arg("--features")
+        .arg(features.join(","))
+        .arg("--target-dir")
+        .arg(Path::new(manifest_dir).join("target").join("no_std_check"))
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn builds_without_std() {
+    build(&[]);
+}
+
+#[test]
+fn builds_with_alloc_only() {
+    build(&["alloc"]);
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "rusqlite")]
+
+use eq_float::{F32, F64};
+use rusqlite::types::Type;
+use rusqlite::{params, Connection, Error};
+
+fn scores() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute(
+        "CREATE TABLE scores (id INTEGER PRIMARY KEY, single REAL, double REAL)",
+        [],
+    )
+    .unwrap();
+    conn
+}
+
+#[test]
+fn round_trip() {
+    let conn = scores();
+    let values = [
+        (F32(1.5), F64(-2.25)),
+        (F32(f32::INFINITY), F64(f64::NEG_INFINITY)),
+        (F32(f32::MIN_POSITIVE), F64(1.0e300)),
+    ];
+    for (single, double) in &values {
+        conn.execute(
+            "INSERT INTO scores (single, double) VALUES (?1, ?2)",
+            params![single, double],
+        )
+        .unwrap();
+    }
+
+    let mut stmt = conn
+        .prepare("SELECT single, double FROM scores ORDER BY id")
+        .unwrap();
+    let wrapped: Vec<(F32, F64)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let raw: Vec<(f32, f64)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    for ((wrapped, raw), expected) in wrapped.iter().zip(&raw).zip(&values) {
+        assert_eq!(wrapped.0 .0.to_bits(), expected.0 .0.to_bits());
+        assert_eq!(wrapped.1 .0.to_bits(), expected.1 .0.to_bits());
+        assert_eq!(wrapped.0 .0.to_bits(), raw.0.to_bits());
+        assert_eq!(wrapped.1 .0.to_bits(), raw.1.to_bits());
+    }
+}
+
+#[test]
+fn integer_and_null_columns() {
+    let conn = scores();
+    conn.execute("INSERT INTO scores (single, double) VALUES (3, NULL)", [])
+        .unwrap();
+
+    let single: F32 = conn
+        .query_row("SELECT single FROM scores", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(single, F32(3.0));
+
+    let double: Option<F64> = conn
+        .query_row("SELECT double FROM scores", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(double, None);
+
+    let err = conn
+        .query_row("SELECT double FROM scores", [], |row| row.get::<_, F64>(0))
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidColumnType(0, _, Type::Null)));
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "serde")]
+
+use eq_float::{F32, F64};
+use serde::Deserialize;
+
+const SPECIAL_F32: [f32; 7] = [
+    0.0,
+    -0.0,
+    1.5,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+    -f32::NAN,
+];
+
+const SPECIAL_F64: [f64; 7] = [
+    0.0,
+    -0.0,
+    1.5,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+    -f64::NAN,
+];
+
+fn nan_payload_f32() -> f32 {
+    f32::from_bits(0x7fa0_0001)
+}
+
+fn nan_payload_f64() -> f64 {
+    f64::from_bits(0xfff4_0000_0000_0001)
+}
+
+#[test]
+fn postcard_round_trip() {
+    for v in SPECIAL_F32.iter().copied().chain(Some(nan_payload_f32())) {
+        let bytes = postcard::to_allocvec(&F32(v)).unwrap();
+        let back: F32 = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(back.0.to_bits(), v.to_bits());
+    }
+
+    for v in SPECIAL_F64.iter().copied().chain(Some(nan_payload_f64())) {
+        let bytes = postcard::to_allocvec(&F64(v)).unwrap();
+        let back: F64 = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(back.0.to_bits(), v.to_bits());
+    }
+}
+
+#[test]
+fn cbor_round_trip() {
+    for v in SPECIAL_F32.iter().copied().chain(Some(nan_payload_f32())) {
+        let bytes = serde_cbor::to_vec(&F32(v)).unwrap();
+        let back: F32 = serde_cbor::from_slice(&bytes).unwrap();
+        assert_eq!(back.0.to_bits(), v.to_bits());
+    }
+
+    for v in SPECIAL_F64.iter().copied().chain(Some(nan_payload_f64())) {
+        let bytes = serde_cbor::to_vec(&F64(v)).unwrap();
+        let back: F64 = serde_cbor::from_slice(&bytes).unwrap();
+        assert_eq!(back.0.to_bits(), v.to_bits());
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct NotNan {
+    #[serde(deserialize_with = "eq_float::serde::deserialize_not_nan")]
+    wrapped: F32,
+    #[serde(deserialize_with = "eq_float::serde::deserialize_not_nan")]
+    primitive: f64,
+}
+
+#[test]
+fn deserialize_not_nan() {
+    let valid: NotNan = ron::from_str("(wrapped: 1.5, primitive: -0.0)").unwrap();
+    assert_eq!(valid.wrapped, F32(1.5));
+    assert_eq!(valid.primitive.to_bits(), (-0.0f64).to_bits());
+
+    let infinite: NotNan = ron::from_str("(wrapped: inf, primitive: -inf)").unwrap();
+    assert_eq!(infinite.wrapped, F32(f32::INFINITY));
+
+    assert!(ron::from_str::<NotNan>("(wrapped: NaN, primitive: 1.0)").is_err());
+    assert!(ron::from_str::<NotNan>("(wrapped: 1.0, primitive: NaN)").is_err());
+
+    let bytes = postcard::to_allocvec(&(F32(f32::NAN), 1.0f64)).unwrap();
+    assert!(postcard::from_bytes::<NotNan>(&bytes).is_err());
+}
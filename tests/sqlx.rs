@@ -0,0 +1,48 @@
+#![cfg(feature = "sqlx")]
+
+use eq_float::{F32, F64};
+use sqlx::{Connection, FromRow, SqliteConnection};
+
+#[derive(Debug, PartialEq, FromRow)]
+struct Score {
+    single: F32,
+    double: Option<F64>,
+}
+
+#[tokio::test]
+async fn sqlite_round_trip() {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE scores (id INTEGER PRIMARY KEY, single REAL NOT NULL, double REAL)")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let scores = [
+        Score {
+            single: F32(1.5),
+            double: Some(F64(-2.25)),
+        },
+        Score {
+            single: F32(f32::INFINITY),
+            double: None,
+        },
+        Score {
+            single: F32(f32::MAX),
+            double: Some(F64(f64::NEG_INFINITY)),
+        },
+    ];
+    for score in &scores {
+        sqlx::query("INSERT INTO scores (single, double) VALUES (?, ?)")
+            .bind(score.single)
+            .bind(score.double)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+    }
+
+    let read: Vec<Score> = sqlx::query_as("SELECT single, double FROM scores ORDER BY id")
+        .fetch_all(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(read, scores);
+}